@@ -0,0 +1,486 @@
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use lang_interpreter::parser::ast::{ClassMember, Node, NodeData, AST};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AstTraversal {
+    DepthFirst,
+    BreadthFirst,
+}
+
+impl AstTraversal {
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "dfs" => Some(AstTraversal::DepthFirst),
+            "bfs" => Some(AstTraversal::BreadthFirst),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AstFormat {
+    Text,
+    Dot,
+}
+
+impl AstFormat {
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "text" => Some(AstFormat::Text),
+            "dot" => Some(AstFormat::Dot),
+            _ => None,
+        }
+    }
+}
+
+/// Generates [NODE_TYPE_NAMES] and [node_type_name] from one list of all [NodeData] variants
+///
+/// [node_type_name] matches exhaustively over [NodeData], so a new variant which is missing from the list fails to compile
+macro_rules! node_types {
+    ($($node_type:ident $({ $($struct_fields:tt)* })? $(( $($tuple_fields:tt)* ))?),* $(,)?) => {
+        /// The names of all node types in the declaration order of [NodeData] (As returned by [node_type_name])
+        pub const NODE_TYPE_NAMES: [&str; [$(stringify!($node_type)),*].len()] = [$(stringify!($node_type)),*];
+
+        /// Returns the name of the [NodeData] variant of `node`
+        pub fn node_type_name(node: &Node) -> &'static str {
+            match node.node_data() {
+                $(NodeData::$node_type $({ $($struct_fields)* })? $(( $($tuple_fields)* ))? => stringify!($node_type),)*
+            }
+        }
+    };
+}
+
+node_types! {
+    List,
+    ParsingError { .. },
+    Assignment,
+    EscapeSequence(..),
+    UnicodeEscapeSequence(..),
+    UnprocessedVariableName(..),
+    VariableName { .. },
+    ArgumentSeparator(..),
+    FunctionCall(..),
+    FunctionCallPreviousNodeValue { .. },
+    FunctionDefinition(..),
+    IfStatementPartIf { .. },
+    IfStatementPartElse(..),
+    IfStatement,
+    LoopStatementPartLoop(..),
+    LoopStatementPartWhile { .. },
+    LoopStatementPartUntil { .. },
+    LoopStatementPartRepeat { .. },
+    LoopStatementPartForEach { .. },
+    LoopStatementPartElse(..),
+    LoopStatement,
+    TryStatementPartTry(..),
+    TryStatementPartSoftTry(..),
+    TryStatementPartNonTry(..),
+    TryStatementPartCatch { .. },
+    TryStatementPartElse(..),
+    TryStatementPartFinally(..),
+    TryStatement,
+    ContinueBreakStatement { .. },
+    Operation(..),
+    Math(..),
+    Condition(..),
+    Return,
+    Throw,
+    IntValue(..),
+    LongValue(..),
+    FloatValue(..),
+    DoubleValue(..),
+    CharValue(..),
+    TextValue(..),
+    NullValue,
+    VoidValue,
+    ArrayValue,
+    StructDefinition(..),
+    ClassDefinition(..),
+}
+
+/// Returns the most important value of `node` (e.g. the function name of a function call) if any
+pub fn node_value(node: &Node) -> Option<String> {
+    match node.node_data() {
+        NodeData::ParsingError { error, message } => Some(format!("{error}: {message}")),
+        NodeData::EscapeSequence(char) => Some(char.to_string()),
+        NodeData::UnicodeEscapeSequence(hex_code_point) => Some(hex_code_point.to_string()),
+        NodeData::UnprocessedVariableName(variable_name) => Some(variable_name.to_string()),
+        NodeData::VariableName { variable_name, .. } => Some(variable_name.to_string()),
+        NodeData::ArgumentSeparator(original_text) => Some(original_text.to_string()),
+        NodeData::FunctionCall(function_name) => Some(function_name.to_string()),
+        NodeData::FunctionDefinition(function_definition) => function_definition.function_name().map(ToString::to_string),
+        NodeData::ContinueBreakStatement { continue_node, .. } => Some(if *continue_node { "continue" } else { "break" }.to_string()),
+        NodeData::Operation(operation_expression) |
+        NodeData::Math(operation_expression) |
+        NodeData::Condition(operation_expression) => Some(operation_expression.operator().to_string()),
+        NodeData::IntValue(value) => Some(value.to_string()),
+        NodeData::LongValue(value) => Some(value.to_string()),
+        NodeData::FloatValue(value) => Some(value.to_string()),
+        NodeData::DoubleValue(value) => Some(value.to_string()),
+        NodeData::CharValue(value) => Some(value.to_string()),
+        NodeData::TextValue(value) => Some(value.to_string()),
+        NodeData::StructDefinition(struct_definition) => struct_definition.struct_name().map(ToString::to_string),
+        NodeData::ClassDefinition(class_definition) => class_definition.class_name().map(ToString::to_string),
+        _ => None,
+    }
+}
+
+/// Returns all direct children of `node` including the nodes of nested ASTs (e.g. function bodies)
+pub fn node_children(node: &Node) -> Vec<&Node> {
+    let mut children = node.child_nodes().iter().collect::<Vec<_>>();
+
+    match node.node_data() {
+        NodeData::FunctionDefinition(function_definition) => {
+            children.extend(function_definition.function_body().nodes());
+        },
+
+        NodeData::IfStatementPartIf { if_body: body, condition } |
+        NodeData::LoopStatementPartWhile { loop_body: body, condition } |
+        NodeData::LoopStatementPartUntil { loop_body: body, condition } => {
+            children.push(condition.node());
+            children.extend(body.nodes());
+        },
+
+        NodeData::LoopStatementPartRepeat { loop_body, var_pointer_node, repeat_count_node } => {
+            children.push(var_pointer_node);
+            children.push(repeat_count_node);
+            children.extend(loop_body.nodes());
+        },
+
+        NodeData::LoopStatementPartForEach { loop_body, var_pointer_node, composite_or_text_node } => {
+            children.push(var_pointer_node);
+            children.push(composite_or_text_node);
+            children.extend(loop_body.nodes());
+        },
+
+        NodeData::IfStatementPartElse(body) |
+        NodeData::LoopStatementPartLoop(body) |
+        NodeData::LoopStatementPartElse(body) |
+        NodeData::TryStatementPartTry(body) |
+        NodeData::TryStatementPartSoftTry(body) |
+        NodeData::TryStatementPartNonTry(body) |
+        NodeData::TryStatementPartElse(body) |
+        NodeData::TryStatementPartFinally(body) => {
+            children.extend(body.nodes());
+        },
+
+        NodeData::TryStatementPartCatch { try_body, errors } => {
+            if let Some(errors) = errors {
+                children.extend(errors);
+            }
+            children.extend(try_body.nodes());
+        },
+
+        NodeData::ContinueBreakStatement { number_node: Some(number_node), .. } => {
+            children.push(number_node);
+        },
+
+        NodeData::Operation(operation_expression) |
+        NodeData::Math(operation_expression) |
+        NodeData::Condition(operation_expression) => {
+            children.extend(operation_expression.left_side_operand());
+            children.extend(operation_expression.middle_operand());
+            children.extend(operation_expression.right_side_operand());
+        },
+
+        NodeData::ClassDefinition(class_definition) => {
+            children.extend(class_definition.parent_classes());
+            children.extend(class_definition.static_members().iter().filter_map(|member| member.value()));
+            children.extend(class_definition.members().iter().filter_map(|member| member.value()));
+            children.extend(class_definition.methods().iter().map(|method| method.body()));
+            children.extend(class_definition.constructors().iter().map(|constructor| constructor.body()));
+        },
+
+        _ => {},
+    }
+
+    children
+}
+
+/// Returns the attributes of `node` which are neither returned by [node_value] nor by [node_children] if any
+fn node_attributes(node: &Node) -> Option<String> {
+    match node.node_data() {
+        NodeData::VariableName { type_constraint, .. } => Some(format!("type_constraint={type_constraint:?}")),
+
+        NodeData::FunctionCallPreviousNodeValue { leading_whitespace, trailing_whitespace } => Some(format!(
+            "leading_whitespace={leading_whitespace:?}, trailing_whitespace={trailing_whitespace:?}",
+        )),
+
+        NodeData::FunctionDefinition(function_definition) => Some(format!(
+            "parameters={}, overloaded={}, combinator={}, return_value_type_constraint={:?}, doc_comment={:?}",
+            node.child_nodes().len(), function_definition.overloaded(), function_definition.combinator(),
+            function_definition.return_value_type_constraint(), function_definition.doc_comment(),
+        )),
+
+        NodeData::TryStatementPartCatch { errors, .. } => Some(format!("errors={:?}", errors.as_ref().map(Vec::len))),
+
+        NodeData::StructDefinition(struct_definition) => Some(format!(
+            "members={:?}",
+            struct_definition.members().iter().
+                    map(|member| (member.name(), member.type_constraint())).
+                    collect::<Vec<_>>(),
+        )),
+
+        NodeData::ClassDefinition(class_definition) => {
+            let format_members = |members: &[ClassMember]| format!("{:?}", members.iter().
+                    map(|member| (
+                        member.name(), member.type_constraint(), member.final_flag(),
+                        member.visibility(), member.value().is_some(),
+                    )).
+                    collect::<Vec<_>>());
+
+            Some(format!(
+                "parent_classes={}, static_members={}, members={}, methods={:?}, constructors={:?}",
+                class_definition.parent_classes().len(),
+                format_members(class_definition.static_members()),
+                format_members(class_definition.members()),
+                class_definition.methods().iter().
+                        map(|method| (method.name(), method.override_flag(), method.visibility())).
+                        collect::<Vec<_>>(),
+                class_definition.constructors().iter().
+                        map(|constructor| constructor.visibility()).
+                        collect::<Vec<_>>(),
+            ))
+        },
+
+        _ => None,
+    }
+}
+
+/// Returns the canonical form of `ast` without positions (One line per node, indented by its depth)
+///
+/// ASTs which are equal (e.g. which only differ in the formatting of the source code) have the same canonical form
+pub fn canonical_ast(ast: &AST) -> String {
+    fn write_node(node: &Node, depth: usize, builder: &mut String) {
+        let _ = write!(builder, "{}{}", "\t".repeat(depth), node_type_name(node));
+        if let Some(value) = node_value(node) {
+            //Argument separators contain the original whitespace around the ","
+            let value = if matches!(node.node_data(), NodeData::ArgumentSeparator(..)) { value.trim() } else { &value };
+
+            let _ = write!(builder, " value={value:?}");
+        }
+        if let Some(attributes) = node_attributes(node) {
+            let _ = write!(builder, " {attributes}");
+        }
+        builder.push('\n');
+
+        for child in node_children(node) {
+            write_node(child, depth + 1, builder);
+        }
+    }
+
+    let mut builder = String::new();
+    for node in ast.nodes() {
+        write_node(node, 0, &mut builder);
+    }
+
+    builder
+}
+
+/// Returns a single line description of `node` without its children
+pub fn describe_node(node: &Node) -> String {
+    let mut builder = format!("{}: Position: {}", node_type_name(node), node.pos().to_compact_string());
+    if let Some(value) = node_value(node) {
+        let _ = write!(builder, ", Value: \"{value}\"");
+    }
+
+    builder
+}
+
+/// Returns the number of nodes in `ast` which have no children
+pub fn count_leaf_nodes(ast: &AST) -> usize {
+    fn count_leaf_nodes(node: &Node) -> usize {
+        let children = node_children(node);
+        if children.is_empty() {
+            1
+        }else {
+            children.into_iter().map(count_leaf_nodes).sum()
+        }
+    }
+
+    ast.nodes().iter().map(count_leaf_nodes).sum()
+}
+
+/// Prints the height, the node count, the leaf node count, and the most common node type of `ast`
+pub fn print_ast_statistics(ast: &AST) {
+    fn count_node_types(node: &Node, node_type_counts: &mut [usize; NODE_TYPE_NAMES.len()]) {
+        let node_type_name = node_type_name(node);
+        if let Some(index) = NODE_TYPE_NAMES.iter().position(|name| *name == node_type_name) {
+            node_type_counts[index] += 1;
+        }
+
+        for child in node_children(node) {
+            count_node_types(child, node_type_counts);
+        }
+    }
+
+    let mut node_type_counts = [0; NODE_TYPE_NAMES.len()];
+    for node in ast.nodes() {
+        count_node_types(node, &mut node_type_counts);
+    }
+
+    println!("AST height: {}", ast_height(ast));
+    println!("Total nodes: {}", node_type_counts.iter().sum::<usize>());
+    println!("Leaf nodes: {}", count_leaf_nodes(ast));
+
+    //The first node type in declaration order is used if multiple node types are equally common
+    let most_common_node_type = node_type_counts.iter().
+            enumerate().
+            filter(|(_, count)| **count > 0).
+            rev().
+            max_by_key(|(_, count)| **count);
+    if let Some((index, count)) = most_common_node_type {
+        println!("Most common node: {} ({count})", NODE_TYPE_NAMES[index]);
+    }
+}
+
+/// Returns the first parsing error node of `ast` in depth-first order
+pub fn find_parsing_error(ast: &AST) -> Option<&Node> {
+    fn find_parsing_error(node: &Node) -> Option<&Node> {
+        if matches!(node.node_data(), NodeData::ParsingError { .. }) {
+            return Some(node);
+        }
+
+        node_children(node).into_iter().find_map(find_parsing_error)
+    }
+
+    ast.nodes().iter().find_map(find_parsing_error)
+}
+
+/// Returns the maximum depth of all nodes in `ast` (The top level nodes have a depth of 0)
+///
+/// Empty ASTs and ASTs without nested nodes have a height of 0
+pub fn ast_height(ast: &AST) -> usize {
+    fn node_height(node: &Node) -> usize {
+        node_children(node).into_iter().
+                map(|child| node_height(child) + 1).
+                max().
+                unwrap_or(0)
+    }
+
+    ast.nodes().iter().map(node_height).max().unwrap_or(0)
+}
+
+/// Prints the tree structure of `ast` with only the node type names
+pub fn print_ast_types_depth_first(ast: &AST) {
+    fn print_node(node: &Node, depth: usize) {
+        println!("{}{}", "\t".repeat(depth), node_type_name(node));
+
+        for child in node_children(node) {
+            print_node(child, depth + 1);
+        }
+    }
+
+    for node in ast.nodes() {
+        print_node(node, 0);
+    }
+}
+
+/// Prints the tree structure of `ast` with one line per node
+///
+/// Every node is numbered and every subtree which is identical to an already printed subtree is printed as
+/// `<same as node N>` instead of being printed again (Leaf nodes are always printed)
+///
+/// If `types_only` is set, only the node type names are printed
+pub fn print_ast_compact_identical_subtrees(ast: &AST, types_only: bool) {
+    fn print_node<'a>(node: &'a Node, depth: usize, types_only: bool, node_count: &mut usize, printed_subtrees: &mut Vec<(&'a Node, usize)>) {
+        let indent = "\t".repeat(depth);
+
+        let children = node_children(node);
+        if !children.is_empty() {
+            let same_node = printed_subtrees.iter().
+                    find(|(printed_node, _)| *printed_node == node);
+            if let Some((_, node_number)) = same_node {
+                println!("{indent}<same as node {node_number}>");
+
+                return;
+            }
+        }
+
+        *node_count += 1;
+        let node_number = *node_count;
+
+        if types_only {
+            println!("{indent}[{node_number}] {}", node_type_name(node));
+        }else {
+            println!("{indent}[{node_number}] {}", describe_node(node));
+        }
+
+        if !children.is_empty() {
+            printed_subtrees.push((node, node_number));
+        }
+
+        for child in children {
+            print_node(child, depth + 1, types_only, node_count, printed_subtrees);
+        }
+    }
+
+    let mut node_count = 0;
+    let mut printed_subtrees = Vec::new();
+    for node in ast.nodes() {
+        print_node(node, 0, types_only, &mut node_count, &mut printed_subtrees);
+    }
+}
+
+/// Prints all nodes of `ast` grouped by their depth (The top level nodes have a depth of 0)
+///
+/// If `types_only` is set, only the node type names are printed
+pub fn print_ast_breadth_first(ast: &AST, types_only: bool) {
+    let mut queue = ast.nodes().iter().map(|node| (node, 0)).collect::<VecDeque<_>>();
+    let mut current_depth = None;
+
+    while let Some((node, depth)) = queue.pop_front() {
+        if current_depth != Some(depth) {
+            println!("Depth {depth}:");
+            current_depth = Some(depth);
+        }
+
+        if types_only {
+            println!("\t{}", node_type_name(node));
+        }else {
+            println!("\t{}", describe_node(node));
+        }
+
+        queue.extend(node_children(node).into_iter().map(|child| (child, depth + 1)));
+    }
+}
+
+/// Prints `ast` as Graphviz DOT graph with one vertex per node and edges from every parent node to its children
+///
+/// The labels contain the node type and the value of the node. If `types_only` is set, the labels only contain the node type
+pub fn print_ast_dot(ast: &AST, types_only: bool) {
+    fn escape_label(label: &str) -> String {
+        label.replace('\\', "\\\\").
+                replace('"', "\\\"").
+                replace('\n', "\\n")
+    }
+
+    fn print_node(node: &Node, parent_id: usize, types_only: bool, node_count: &mut usize) {
+        *node_count += 1;
+        let node_id = *node_count;
+
+        let mut label = node_type_name(node).to_string();
+        if !types_only && let Some(value) = node_value(node) {
+            let _ = write!(label, "\n\"{value}\"");
+        }
+
+        println!("\tnode{node_id} [label=\"{}\"];", escape_label(&label));
+        println!("\tnode{parent_id} -> node{node_id};");
+
+        for child in node_children(node) {
+            print_node(child, node_id, types_only, node_count);
+        }
+    }
+
+    println!("digraph AST {{");
+    println!("\tnode [shape=box];");
+    println!("\tnode0 [label=\"AST\"];");
+
+    let mut node_count = 0;
+    for node in ast.nodes() {
+        print_node(node, 0, types_only, &mut node_count);
+    }
+
+    println!("}}");
+}
@@ -0,0 +1,168 @@
+use lang_interpreter::parser::ast::{Node, AST};
+use crate::ast;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Axis {
+    Child,
+    Descendant,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Attribute {
+    Value,
+    Line,
+}
+
+impl Attribute {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "value" => Some(Attribute::Value),
+            "line" => Some(Attribute::Line),
+            _ => None,
+        }
+    }
+
+    fn matches(&self, node: &Node, value: &str) -> bool {
+        match self {
+            Attribute::Value => ast::node_value(node).is_some_and(|node_value| node_value == value),
+            Attribute::Line => node.pos().line_number_from().to_string() == value,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Step {
+    axis: Axis,
+    node_type: Box<str>,
+    attribute: Option<(Attribute, Box<str>)>,
+}
+
+impl Step {
+    fn matches(&self, node: &Node) -> bool {
+        (&*self.node_type == "*" || &*self.node_type == ast::node_type_name(node)) &&
+                self.attribute.as_ref().is_none_or(|(attribute, value)| attribute.matches(node, value))
+    }
+}
+
+/// A simple XPath-like query for AST nodes
+///
+/// * `//NodeType` matches all descendants of type `NodeType`
+/// * `/NodeType` matches all direct children of type `NodeType`
+/// * `*` matches nodes of any type
+/// * `NodeType@attr=value` additionally matches the attribute `attr` (`value` or `line`) of the node.
+///   The value can be quoted with `"` if it contains `/`
+#[derive(Debug)]
+pub struct AstQuery {
+    steps: Vec<Step>,
+}
+
+impl AstQuery {
+    pub fn parse(query: &str) -> Result<Self, String> {
+        let mut steps = Vec::new();
+
+        let mut query = query;
+        while !query.is_empty() {
+            let axis = if let Some(rest) = query.strip_prefix("//") {
+                query = rest;
+
+                Axis::Descendant
+            }else if let Some(rest) = query.strip_prefix("/") {
+                query = rest;
+
+                Axis::Child
+            }else {
+                return Err(format!("Expected \"/\" or \"//\" at \"{query}\""));
+            };
+
+            let node_type_end = query.find(['/', '@']).unwrap_or(query.len());
+            let node_type = &query[..node_type_end];
+            if node_type.is_empty() {
+                return Err("Node type must not be empty".to_string());
+            }
+            query = &query[node_type_end..];
+
+            let mut attribute = None;
+            if let Some(rest) = query.strip_prefix("@") {
+                let Some((attribute_name, rest)) = rest.split_once('=') else {
+                    return Err(format!("Expected \"=\" after attribute at \"@{rest}\""));
+                };
+
+                let Some(attribute_name) = Attribute::from_name(attribute_name) else {
+                    return Err(format!("Unknown attribute \"{attribute_name}\" (Must be \"value\" or \"line\")"));
+                };
+
+                let value;
+                if let Some(rest) = rest.strip_prefix('"') {
+                    let Some(value_end) = rest.find('"') else {
+                        return Err("Missing closing \" of attribute value".to_string());
+                    };
+
+                    value = &rest[..value_end];
+                    query = &rest[value_end + 1..];
+                }else {
+                    let value_end = rest.find('/').unwrap_or(rest.len());
+
+                    value = &rest[..value_end];
+                    query = &rest[value_end..];
+                }
+
+                attribute = Some((attribute_name, Box::from(value)));
+            }
+
+            steps.push(Step {
+                axis,
+                node_type: Box::from(node_type),
+                attribute,
+            });
+        }
+
+        if steps.is_empty() {
+            return Err("Query must not be empty".to_string());
+        }
+
+        Ok(Self { steps })
+    }
+
+    /// Returns all nodes of `ast` which are matched by this query in depth-first order
+    pub fn find<'a>(&self, ast: &'a AST) -> Vec<&'a Node> {
+        let mut context: Option<Vec<&'a Node>> = None;
+
+        for step in &self.steps {
+            let candidates = match (&context, step.axis) {
+                (None, Axis::Child) => ast.nodes().iter().collect(),
+                (None, Axis::Descendant) => ast.nodes().iter().
+                        flat_map(|node| std::iter::once(node).chain(descendants(node))).
+                        collect(),
+
+                (Some(context), Axis::Child) => context.iter().
+                        flat_map(|node| ast::node_children(node)).
+                        collect(),
+                (Some(context), Axis::Descendant) => context.iter().
+                        flat_map(|node| descendants(node)).
+                        collect::<Vec<_>>(),
+            };
+
+            let mut matches: Vec<&Node> = Vec::new();
+            for node in candidates {
+                if step.matches(node) && !matches.iter().any(|matched_node| std::ptr::eq(*matched_node, node)) {
+                    matches.push(node);
+                }
+            }
+
+            context = Some(matches);
+        }
+
+        context.unwrap_or_default()
+    }
+}
+
+fn descendants(node: &Node) -> Vec<&Node> {
+    let mut descendants = Vec::new();
+
+    for child in ast::node_children(node) {
+        descendants.push(child);
+        descendants.append(&mut self::descendants(child));
+    }
+
+    descendants
+}
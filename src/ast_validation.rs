@@ -0,0 +1,207 @@
+use std::fmt::{Display, Formatter};
+use lang_interpreter::lexer::CodePosition;
+use lang_interpreter::parser::ast::{Node, NodeData, AST};
+use crate::ast;
+
+/// A node which does not fulfill a structural invariant of the AST
+#[derive(Debug)]
+pub struct Violation {
+    node_type: &'static str,
+    pos: CodePosition,
+    message: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}: {}", self.node_type, self.pos.to_compact_string(), self.message)
+    }
+}
+
+/// Checks the structural invariants of all nodes of `ast` and returns all violations in depth-first order
+///
+/// The invariants are the child node layouts which are created by the parser
+/// (e.g. every assignment has exactly two child nodes and every operation has exactly as many operands as the arity of its operator)
+pub fn validate(ast: &AST) -> Vec<Violation> {
+    fn validate_node(node: &Node, violations: &mut Vec<Violation>) {
+        if let Some(message) = check_node(node) {
+            violations.push(Violation {
+                node_type: ast::node_type_name(node),
+                pos: node.pos(),
+                message,
+            });
+        }
+
+        for child in ast::node_children(node) {
+            validate_node(child, violations);
+        }
+    }
+
+    let mut violations = Vec::new();
+    for node in ast.nodes() {
+        validate_node(node, &mut violations);
+    }
+
+    violations
+}
+
+fn check_node(node: &Node) -> Option<String> {
+    let child_nodes = node.child_nodes();
+
+    match node.node_data() {
+        NodeData::Assignment => {
+            if child_nodes.len() != 2 {
+                return Some(format!("Expected 2 child nodes (lvalue and rvalue), but got {}", child_nodes.len()));
+            }
+        },
+
+        NodeData::Return => {
+            if child_nodes.len() > 1 {
+                return Some(format!("Expected at most 1 child node, but got {}", child_nodes.len()));
+            }
+        },
+
+        NodeData::Throw => {
+            if child_nodes.is_empty() || child_nodes.len() > 2 {
+                return Some(format!("Expected 1 or 2 child nodes, but got {}", child_nodes.len()));
+            }
+        },
+
+        NodeData::IfStatement => {
+            return check_statement_parts(
+                child_nodes,
+                |node_data| matches!(node_data, NodeData::IfStatementPartIf { .. }),
+                |node_data| matches!(node_data, NodeData::IfStatementPartIf { .. } | NodeData::IfStatementPartElse(..)),
+                |node_data| matches!(node_data, NodeData::IfStatementPartElse(..)),
+            );
+        },
+
+        NodeData::LoopStatement => {
+            return check_statement_parts(
+                child_nodes,
+                |node_data| !matches!(node_data, NodeData::LoopStatementPartElse(..)),
+                |node_data| matches!(
+                    node_data,
+                    NodeData::LoopStatementPartLoop(..) | NodeData::LoopStatementPartWhile { .. } |
+                    NodeData::LoopStatementPartUntil { .. } | NodeData::LoopStatementPartRepeat { .. } |
+                    NodeData::LoopStatementPartForEach { .. } | NodeData::LoopStatementPartElse(..)
+                ),
+                |node_data| matches!(node_data, NodeData::LoopStatementPartElse(..)),
+            );
+        },
+
+        NodeData::TryStatement => {
+            return check_statement_parts(
+                child_nodes,
+                |node_data| matches!(
+                    node_data,
+                    NodeData::TryStatementPartTry(..) | NodeData::TryStatementPartSoftTry(..) | NodeData::TryStatementPartNonTry(..)
+                ),
+                |node_data| matches!(
+                    node_data,
+                    NodeData::TryStatementPartTry(..) | NodeData::TryStatementPartSoftTry(..) |
+                    NodeData::TryStatementPartNonTry(..) | NodeData::TryStatementPartCatch { .. } |
+                    NodeData::TryStatementPartElse(..) | NodeData::TryStatementPartFinally(..)
+                ),
+                |node_data| matches!(node_data, NodeData::TryStatementPartFinally(..)),
+            );
+        },
+
+        NodeData::Operation(operation_expression) |
+        NodeData::Math(operation_expression) |
+        NodeData::Condition(operation_expression) => {
+            let operand_count = [
+                operation_expression.left_side_operand(),
+                operation_expression.middle_operand(),
+                operation_expression.right_side_operand(),
+            ].into_iter().flatten().count();
+
+            let operator = operation_expression.operator();
+            if operand_count != operator.arity() {
+                return Some(format!(
+                    "Expected {} operand(s) for operator \"{}\", but got {operand_count}",
+                    operator.arity(), operator.symbol(),
+                ));
+            }
+
+            if operation_expression.left_side_operand().is_none() {
+                return Some("Left side operand is missing".to_string());
+            }
+        },
+
+        //Values, names, and statement parts store their content in the node data
+        NodeData::ParsingError { .. } |
+        NodeData::EscapeSequence(..) |
+        NodeData::UnicodeEscapeSequence(..) |
+        NodeData::UnprocessedVariableName(..) |
+        NodeData::VariableName { .. } |
+        NodeData::ArgumentSeparator(..) |
+        NodeData::IfStatementPartIf { .. } |
+        NodeData::IfStatementPartElse(..) |
+        NodeData::LoopStatementPartLoop(..) |
+        NodeData::LoopStatementPartWhile { .. } |
+        NodeData::LoopStatementPartUntil { .. } |
+        NodeData::LoopStatementPartRepeat { .. } |
+        NodeData::LoopStatementPartForEach { .. } |
+        NodeData::LoopStatementPartElse(..) |
+        NodeData::TryStatementPartTry(..) |
+        NodeData::TryStatementPartSoftTry(..) |
+        NodeData::TryStatementPartNonTry(..) |
+        NodeData::TryStatementPartCatch { .. } |
+        NodeData::TryStatementPartElse(..) |
+        NodeData::TryStatementPartFinally(..) |
+        NodeData::ContinueBreakStatement { .. } |
+        NodeData::IntValue(..) |
+        NodeData::LongValue(..) |
+        NodeData::FloatValue(..) |
+        NodeData::DoubleValue(..) |
+        NodeData::CharValue(..) |
+        NodeData::TextValue(..) |
+        NodeData::NullValue |
+        NodeData::VoidValue |
+        NodeData::StructDefinition(..) |
+        NodeData::ClassDefinition(..) => {
+            if !child_nodes.is_empty() {
+                return Some(format!("Expected no child nodes, but got {}", child_nodes.len()));
+            }
+        },
+
+        NodeData::List |
+        NodeData::FunctionCall(..) |
+        NodeData::FunctionCallPreviousNodeValue { .. } |
+        NodeData::FunctionDefinition(..) |
+        NodeData::ArrayValue => {},
+    }
+
+    None
+}
+
+/// Checks the parts of an if, loop, or try statement
+///
+/// The first part must fulfill `is_first_part`, all parts must fulfill `is_part`,
+/// and parts which fulfill `is_last_part` must be the last part
+fn check_statement_parts(
+    parts: &[Node],
+    is_first_part: impl Fn(&NodeData) -> bool,
+    is_part: impl Fn(&NodeData) -> bool,
+    is_last_part: impl Fn(&NodeData) -> bool,
+) -> Option<String> {
+    let Some(first_part) = parts.first() else {
+        return Some("Expected at least 1 statement part, but got none".to_string());
+    };
+
+    if !is_first_part(first_part.node_data()) {
+        return Some(format!("Statement must not start with {}", ast::node_type_name(first_part)));
+    }
+
+    for (i, part) in parts.iter().enumerate() {
+        if !is_part(part.node_data()) {
+            return Some(format!("{} is not a valid statement part", ast::node_type_name(part)));
+        }
+
+        if i + 1 < parts.len() && is_last_part(part.node_data()) {
+            return Some(format!("{} must be the last statement part", ast::node_type_name(part)));
+        }
+    }
+
+    None
+}
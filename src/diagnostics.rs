@@ -0,0 +1,56 @@
+use std::env;
+
+const DEFAULT_WIDTH: usize = 80;
+
+/// Returns the width of the terminal from the `COLUMNS` environment variable or 80 if it is not set
+pub fn terminal_width() -> usize {
+    env::var("COLUMNS").ok().
+            and_then(|columns| columns.trim().parse().ok()).
+            filter(|width| *width > 0).
+            unwrap_or(DEFAULT_WIDTH)
+}
+
+/// Word-wraps every line of `msg` at `width` characters
+///
+/// Wrapped lines keep the indentation of their original line. Words which are longer than `width` are not split.
+pub fn wrap_diagnostic(msg: &str, width: usize) -> String {
+    msg.split('\n').
+            map(|line| wrap_line(line, width)).
+            collect::<Vec<_>>().
+            join("\n")
+}
+
+fn wrap_line(line: &str, width: usize) -> String {
+    if line.chars().count() <= width {
+        return line.to_string();
+    }
+
+    let content = line.trim_start();
+    let indent = &line[..line.len() - content.len()];
+    let indent_len = indent.chars().count();
+
+    let mut wrapped_line = String::from(indent);
+    let mut current_len = indent_len;
+    let mut line_start = true;
+    for word in content.split(' ').filter(|word| !word.is_empty()) {
+        let word_len = word.chars().count();
+
+        if !line_start && current_len + 1 + word_len > width {
+            wrapped_line.push('\n');
+            wrapped_line += indent;
+            current_len = indent_len;
+            line_start = true;
+        }
+
+        if !line_start {
+            wrapped_line.push(' ');
+            current_len += 1;
+        }
+
+        wrapped_line += word;
+        current_len += word_len;
+        line_start = false;
+    }
+
+    wrapped_line
+}
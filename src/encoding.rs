@@ -0,0 +1,43 @@
+use std::borrow::Cow;
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum SourceEncoding {
+    #[default]
+    Utf8,
+    Detect,
+}
+
+impl SourceEncoding {
+    /// Minimal confidence of the encoding detection which is required to use the detected encoding
+    const MIN_DETECTION_CONFIDENCE: f32 = 0.9;
+
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "utf-8" | "utf8" => Some(SourceEncoding::Utf8),
+            "detect" => Some(SourceEncoding::Detect),
+            _ => None,
+        }
+    }
+
+    /// Decodes `code` with this encoding
+    ///
+    /// If the encoding should be detected and the detection fails or is not confident enough,
+    /// a warning is logged and UTF-8 is used instead
+    pub fn decode<'a>(&self, code: &'a [u8]) -> Cow<'a, str> {
+        if *self == SourceEncoding::Detect {
+            let (charset, confidence, _) = chardet::detect(code);
+            if confidence < Self::MIN_DETECTION_CONFIDENCE {
+                log::warn!(
+                    "Encoding detection is not confident enough ({:.0}% for \"{charset}\"), falling back to UTF-8",
+                    confidence * 100.0,
+                );
+            }else if let Some(encoding) = encoding_rs::Encoding::for_label(chardet::charset2encoding(&charset).as_bytes()) {
+                return encoding.decode(code).0;
+            }else {
+                log::warn!("Detected encoding \"{charset}\" is not supported, falling back to UTF-8");
+            }
+        }
+
+        String::from_utf8_lossy(code)
+    }
+}
@@ -0,0 +1,32 @@
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum HashAlgorithm {
+    Sha256,
+    Blake3,
+}
+
+impl HashAlgorithm {
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "SHA256" | "sha256" => Some(HashAlgorithm::Sha256),
+            "BLAKE3" | "blake3" => Some(HashAlgorithm::Blake3),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "SHA256",
+            HashAlgorithm::Blake3 => "BLAKE3",
+        }
+    }
+
+    /// Returns the hash of `bytes` as lowercase hex string
+    pub fn hash_hex(&self, bytes: &[u8]) -> String {
+        match self {
+            HashAlgorithm::Sha256 => format!("{:x}", Sha256::digest(bytes)),
+            HashAlgorithm::Blake3 => blake3::hash(bytes).to_hex().to_string(),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::path::Path;
+
+/// The names of all GLOBAL_OPTIONs
+pub const GLOBAL_OPTIONS: [&str; 4] = [
+    "--no-stdin-auto",
+    "--hint",
+    "--output-json-pretty",
+    "--output-json-compact",
+];
+
+/// The names of all COMMANDs
+pub const COMMANDS: [&str; 10] = [
+    "-printAST",
+    "-printTokens",
+    "--check-file-exists",
+    "--check-imports-only",
+    "--generate-test-skeleton",
+    "--introspect-api",
+    "--print-token-types",
+    "--print-ast-node-types",
+    "--count-lines",
+    "--help",
+];
+
+/// The names of all EXECUTION_ARGs (Without the values of "--NAME=VALUE" arguments)
+pub const EXECUTION_ARGS: [&str; 23] = [
+    "-printTranslations",
+    "-printReturnedValue",
+    "--print-return-type",
+    "-warnings",
+    "--check-assertions",
+    "--print-platform-api-calls",
+    "--encoding",
+    "--stdin-mode",
+    "--no-platform-api",
+    "--wrap-diagnostics",
+    "--width",
+    "--capture-stderr-to",
+    "--execution-log",
+    "--execution-summary",
+    "--list-lang-args",
+    "--format",
+    "--print-file-hash",
+    "--log-level",
+    "--log-file",
+    "--eval-args",
+    "--execution-args-file",
+    "-langArgs",
+    "--",
+];
+
+/// The names of all TOKEN_ARGs (Without the values of "--NAME=VALUE" arguments)
+pub const TOKEN_ARGS: [&str; 6] = [
+    "--count",
+    "--strip-comments",
+    "--strip-whitespace",
+    "--hash-only",
+    "--print-token-span",
+    "--encoding",
+];
+
+/// The names of all AST_ARGs (Without the values of "--NAME=VALUE" arguments)
+pub const AST_ARGS: [&str; 11] = [
+    "--ast-traversal",
+    "--format",
+    "--types-only",
+    "--leaf-count",
+    "--hash-only",
+    "--height-only",
+    "--statistics-only",
+    "--ast-validate",
+    "--compact-identical-subtrees",
+    "--ast-query",
+    "--encoding",
+];
+
+/// Returns the candidate which is the most similar to `name` if it is similar enough to be a misspelling of it
+///
+/// The similarity is the case-insensitive Levenshtein distance which must be at most a quarter of the length of the candidate
+fn find_similar_name<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let name_lowercase = name.to_lowercase();
+
+    candidates.iter().
+            filter(|candidate| **candidate != name).
+            map(|candidate| (*candidate, strsim::levenshtein(&name_lowercase, &candidate.to_lowercase()))).
+            filter(|(candidate, distance)| *distance <= candidate.len() / 4).
+            min_by_key(|(_, distance)| *distance).
+            map(|(candidate, _)| candidate)
+}
+
+/// Prints a hint with the most similar argument of `candidates` if `arg` is a misspelling of it
+///
+/// The value of "--NAME=VALUE" arguments is kept in the suggestion
+pub fn print_similar_arg_hint(arg: &str, candidates: &[&str]) {
+    if GLOBAL_OPTIONS.contains(&arg) {
+        eprintln!("Hint: \"{arg}\" is a GLOBAL_OPTION and must be placed before COMMAND, \"-e CODE\", or FILE");
+
+        return;
+    }
+
+    let (name, value) = match arg.split_once('=') {
+        Some((name, value)) => (name, Some(value)),
+        None => (arg, None),
+    };
+
+    let Some(similar_name) = find_similar_name(name, candidates) else {
+        return;
+    };
+
+    match value {
+        Some(value) => eprintln!("Hint: Did you mean \"{similar_name}={value}\" instead of \"{arg}\"?"),
+        None => eprintln!("Hint: Did you mean \"{similar_name}\" instead of \"{arg}\"?"),
+    }
+}
+
+/// Prints a hint for an unknown COMMAND `arg`
+pub fn print_unknown_command_hint(arg: &str) {
+    let name = arg.split_once('=').map_or(arg, |(name, _)| name);
+    if EXECUTION_ARGS.contains(&name) {
+        eprintln!("Hint: \"{arg}\" is an EXECUTION_ARG and must be placed after FILE or after \"-e CODE\"");
+
+        return;
+    }
+
+    let candidates = COMMANDS.iter().chain(&GLOBAL_OPTIONS).copied().collect::<Vec<_>>();
+    print_similar_arg_hint(arg, &candidates);
+}
+
+/// Prints a hint with the usage of a COMMAND if its file argument is missing (e.g. `command_usage`: "-printAST [AST_ARGs]... FILE")
+pub fn print_missing_file_hint(binary_name: Option<&str>, command_usage: &str) {
+    let binary_name = binary_name.unwrap_or("lang");
+
+    eprintln!("Hint: The path of the Lang file is missing, usage: \"{binary_name} {command_usage}\"");
+}
+
+/// Prints a hint if `lang_file` does not look like a Lang file
+pub fn print_lang_file_hint(lang_file: &str) {
+    let Some(extension) = Path::new(lang_file).extension() else {
+        return;
+    };
+
+    if extension != "lang" {
+        eprintln!(
+            "Hint: \"{lang_file}\" is executed as Lang file, but Lang files usually have the \".lang\" file extension (not \".{}\")",
+            extension.to_string_lossy(),
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use std::path::{Path, PathBuf};
+use lang_interpreter::lexer::CodePosition;
+use lang_interpreter::parser::ast::{Node, NodeData, AST};
+use lang_interpreter::parser::Parser;
+use crate::ast;
+
+const LINKER_FUNCTION_NAMES: [&str; 4] = ["link", "bindLibrary", "include", "loadModule"];
+
+/// A file which is imported by a linker function call with a static file path
+#[derive(Debug)]
+pub struct Import {
+    pub function_name: Box<str>,
+    pub path: PathBuf,
+    pub pos: CodePosition,
+}
+
+impl Import {
+    /// Returns `true` if the imported file is a Lang file which can import other files (Modules are excluded)
+    pub fn is_lang_file(&self) -> bool {
+        !self.function_name.ends_with(".loadModule")
+    }
+}
+
+/// A linker function call whose file path is not static text and can only be resolved during execution
+#[derive(Debug)]
+pub struct DynamicImport {
+    pub function_name: Box<str>,
+    pub pos: CodePosition,
+}
+
+/// Returns all imports of `ast` in depth-first order
+///
+/// Only linker function calls with a text value as file path are returned as [Import], because other file paths
+/// can only be resolved during execution. These linker function calls are returned as [DynamicImport] instead.
+/// Relative file paths are resolved relative to `lang_path`.
+pub fn find_imports(ast: &AST, lang_path: &Path) -> (Vec<Import>, Vec<DynamicImport>) {
+    fn find_imports(node: &Node, lang_path: &Path, imports: &mut Vec<Import>, dynamic_imports: &mut Vec<DynamicImport>) {
+        if let NodeData::FunctionCall(function_name) = node.node_data() && is_linker_function_name(function_name) {
+            match static_text_argument(node.child_nodes()) {
+                Some(file) => imports.push(Import {
+                    function_name: function_name.clone(),
+                    path: lang_path.join(file),
+                    pos: node.pos(),
+                }),
+
+                None => dynamic_imports.push(DynamicImport {
+                    function_name: function_name.clone(),
+                    pos: node.pos(),
+                }),
+            }
+        }
+
+        for child in ast::node_children(node) {
+            find_imports(child, lang_path, imports, dynamic_imports);
+        }
+    }
+
+    let mut imports = Vec::new();
+    let mut dynamic_imports = Vec::new();
+    for node in ast.nodes() {
+        find_imports(node, lang_path, &mut imports, &mut dynamic_imports);
+    }
+
+    (imports, dynamic_imports)
+}
+
+/// Calls `check_import` for every file which is imported by `lang_file` with the content `code`
+/// (And transitively by the imported Lang files) and returns the number of imports which failed the check
+///
+/// Every file is only checked once (Circular imports are allowed). `check_import` returns the content of the imported file
+/// if it is a Lang file whose imports should be checked as well or the reason why the import failed the check which is printed.
+/// A note is printed for every linker function call whose file path can not be checked, because it is not static text.
+pub fn walk_imports(lang_file: &Path, code: Vec<u8>, mut check_import: impl FnMut(&Import) -> Result<Option<Vec<u8>>, String>) -> usize {
+    let mut failed_import_count = 0;
+
+    //Compare canonical paths to prevent checking the same file multiple times (e.g. for circular imports)
+    let mut checked_files = vec![lang_file.canonicalize().unwrap_or_else(|_| lang_file.to_path_buf())];
+    let mut files_to_walk = vec![(lang_file.to_path_buf(), code)];
+
+    while let Some((file, code)) = files_to_walk.pop() {
+        let ast = Parser::new().parse_lines(String::from_utf8_lossy(&code)).unwrap();
+
+        let (imports, dynamic_imports) = find_imports(&ast, &lang_path_of(&file));
+
+        for dynamic_import in dynamic_imports {
+            eprintln!(
+                "Note: The file path of \"{}\" in \"{}\" at {} can only be resolved during execution and is not checked",
+                dynamic_import.function_name, file.display(), dynamic_import.pos.to_compact_string(),
+            );
+        }
+
+        for import in imports {
+            let canonical_path = import.path.canonicalize().unwrap_or_else(|_| import.path.clone());
+            if checked_files.contains(&canonical_path) {
+                continue;
+            }
+            checked_files.push(canonical_path);
+
+            match check_import(&import) {
+                Ok(Some(import_code)) if import.is_lang_file() => files_to_walk.push((import.path, import_code)),
+                Ok(_) => {},
+                Err(reason) => {
+                    eprintln!(
+                        "{reason}: \"{}\" (Imported by \"{}\" at {} with \"{}\")",
+                        import.path.display(), file.display(), import.pos.to_compact_string(), import.function_name,
+                    );
+
+                    failed_import_count += 1;
+                },
+            }
+        }
+    }
+
+    failed_import_count
+}
+
+/// Returns the first argument of `argument_nodes` if it only consists of static text
+fn static_text_argument(argument_nodes: &[Node]) -> Option<String> {
+    let mut text = String::new();
+
+    for node in argument_nodes {
+        match node.node_data() {
+            NodeData::ArgumentSeparator(..) => break,
+
+            NodeData::TextValue(value) => text += value,
+            NodeData::EscapeSequence(char) | NodeData::CharValue(char) => text.push(*char),
+
+            //Names without a variable prefix (e.g. "mp.lang" of "imp.lang" which is parsed as char "i" followed by "mp.lang") are interpreted as text
+            NodeData::UnprocessedVariableName(variable_name) if !variable_name.starts_with(['$', '&']) => {
+                text += variable_name;
+            },
+
+            _ => return None,
+        }
+    }
+
+    (!text.is_empty()).then_some(text)
+}
+
+fn is_linker_function_name(function_name: &str) -> bool {
+    let Some((prefix, name)) = function_name.split_once('.') else {
+        return false;
+    };
+
+    matches!(prefix, "ln" | "linker") && LINKER_FUNCTION_NAMES.contains(&name)
+}
+
+/// Returns the directory relative to which imports of `lang_file` are resolved
+pub fn lang_path_of(lang_file: &Path) -> PathBuf {
+    match lang_file.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    }
+}
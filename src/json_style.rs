@@ -0,0 +1,40 @@
+use std::io::{self, IsTerminal};
+use serde::Serialize;
+use serde_json::ser::PrettyFormatter;
+use serde_json::{Serializer, Value};
+
+/// The layout of JSON which is printed to standard output
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum JsonStyle {
+    /// 4-space indentation with one field per line
+    Pretty,
+    /// Minified without any whitespace
+    Compact,
+}
+
+impl JsonStyle {
+    /// Returns [JsonStyle::Pretty] if standard output is a terminal and [JsonStyle::Compact] otherwise (e.g. for pipes)
+    pub fn detect() -> Self {
+        if io::stdout().is_terminal() {
+            JsonStyle::Pretty
+        }else {
+            JsonStyle::Compact
+        }
+    }
+
+    pub fn format(&self, value: &Value) -> String {
+        match self {
+            JsonStyle::Pretty => {
+                let mut json = Vec::new();
+                let mut serializer = Serializer::with_formatter(&mut json, PrettyFormatter::with_indent(b"    "));
+
+                //Serializing a Value into a Vec can not fail
+                value.serialize(&mut serializer).unwrap();
+
+                String::from_utf8(json).unwrap()
+            },
+
+            JsonStyle::Compact => value.to_string(),
+        }
+    }
+}
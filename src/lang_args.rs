@@ -0,0 +1,65 @@
+use lang_interpreter::interpreter::data::{DataObject, DataObjectRef};
+use lang_interpreter::interpreter::{lii, Interpreter};
+use serde_json::Value;
+
+/// Parses `json` which must be a JSON array of lang args
+pub fn parse_json_lang_args(json: &str) -> Result<Vec<Value>, String> {
+    let value = serde_json::from_str::<Value>(json).map_err(|e| format!("Invalid JSON: {e}"))?;
+
+    let Value::Array(lang_args) = value else {
+        return Err("Lang args must be a JSON array".to_string());
+    };
+
+    //Check for unsupported values early
+    for lang_arg in &lang_args {
+        json_to_data_object(lang_arg)?;
+    }
+
+    Ok(lang_args)
+}
+
+/// Sets the `&LANG_ARGS` variable of `interpreter` to the typed values of `lang_args`
+///
+/// The interpreter must be created with lang args (e.g. an empty array), otherwise the values are not visible inside of functions
+pub fn set_json_lang_args(interpreter: &mut Interpreter, lang_args: &[Value]) -> Result<(), String> {
+    let lang_args = lang_args.iter().
+            map(json_to_data_object).
+            collect::<Result<Box<[_]>, _>>()?;
+
+    let lang_args = DataObject::with_update(|data_object| {
+        Ok(data_object.set_array(lang_args)?.set_final_data(true))
+    }).map_err(|e| e.to_string())?;
+
+    lii::set_var(interpreter, "&LANG_ARGS", DataObjectRef::new(lang_args), true).map_err(|e| e.to_string())
+}
+
+fn json_to_data_object(value: &Value) -> Result<DataObjectRef, String> {
+    let array_values = match value {
+        Value::Object(_) => return Err("JSON objects are not supported as lang args".to_string()),
+
+        Value::Array(values) => Some(values.iter().
+                map(json_to_data_object).
+                collect::<Result<Box<[_]>, _>>()?),
+
+        _ => None,
+    };
+
+    DataObject::with_update(|data_object| {
+        match value {
+            Value::Null => data_object.set_null(),
+            Value::Bool(value) => data_object.set_bool(*value),
+            Value::Number(value) => {
+                if let Some(value) = value.as_i64() {
+                    match i32::try_from(value) {
+                        Ok(value) => data_object.set_int(value),
+                        Err(_) => data_object.set_long(value),
+                    }
+                }else {
+                    data_object.set_double(value.as_f64().unwrap_or(f64::NAN))
+                }
+            },
+            Value::String(value) => data_object.set_text(&**value),
+            Value::Array(_) | Value::Object(_) => data_object.set_array(array_values.unwrap_or_default()),
+        }
+    }).map(DataObjectRef::new).map_err(|e| e.to_string())
+}
@@ -0,0 +1,71 @@
+use std::fs::File;
+use std::io::{self, Write};
+use std::sync::Mutex;
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// The log level which is used if the "--log-level" EXECUTION_ARG is not set
+///
+/// Warnings (e.g. of the encoding detection) are logged by default, because they must be visible on standard error
+pub const DEFAULT_LEVEL: LevelFilter = LevelFilter::Warn;
+
+/// Parses the value of the "--log-level" argument
+pub fn level_filter_from_arg(arg: &str) -> Option<LevelFilter> {
+    match arg {
+        "error" => Some(LevelFilter::Error),
+        "warn" => Some(LevelFilter::Warn),
+        "info" => Some(LevelFilter::Info),
+        "debug" => Some(LevelFilter::Debug),
+        "trace" => Some(LevelFilter::Trace),
+        _ => None,
+    }
+}
+
+/// Writes log records to standard error or to a log file
+struct Logger {
+    output: Mutex<Box<dyn Write + Send>>,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        if let Ok(mut output) = self.output.lock() {
+            let _ = writeln!(output, "[{}] {}", record.level(), record.args());
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut output) = self.output.lock() {
+            let _ = output.flush();
+        }
+    }
+}
+
+/// Initializes the global logger which logs all records up to `level`
+///
+/// If `log_file` is set, the records are written to that file instead of standard error
+pub fn init(level: LevelFilter, log_file: Option<&str>) -> Result<(), String> {
+    let output: Box<dyn Write + Send> = match log_file {
+        Some(log_file) => Box::new(File::create(log_file).
+                map_err(|e| format!("Log file \"{log_file}\" can not be created {e}"))?),
+        None => Box::new(io::stderr()),
+    };
+
+    log::set_boxed_logger(Box::new(Logger {
+        output: Mutex::new(output),
+    })).map_err(|e| e.to_string())?;
+    log::set_max_level(level);
+
+    Ok(())
+}
+
+/// Initializes the global logger with [DEFAULT_LEVEL] which writes to standard error for all entry paths without the "--log-level" EXECUTION_ARG
+pub fn init_default() -> Result<(), String> {
+    init(DEFAULT_LEVEL, None)
+}
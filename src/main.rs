@@ -1,12 +1,96 @@
 use std::env;
-use std::fs::File;
-use std::io::Read;
-use std::path::Path;
+use std::fs::{self, File};
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
-use lang_interpreter::interpreter::{conversions, lii, ErrorOutputFlag, Interpreter};
+use std::time::{Duration, Instant};
+use lang_interpreter::interpreter::{conversions, lii, ErrorOutputFlag, Interpreter, InterpretingError};
 use lang_interpreter::interpreter::platform::{DefaultPlatformAPI, PlatformAPI};
 use lang_interpreter::lexer::{CodePosition, Lexer};
 use lang_interpreter::parser::Parser;
+use crate::ast::{AstFormat, AstTraversal};
+use crate::ast_query::AstQuery;
+use crate::encoding::SourceEncoding;
+use crate::hash::HashAlgorithm;
+use crate::json_style::JsonStyle;
+use crate::platform::{DebugPlatformAPI, DiagnosticWrap, ErrorOutputPlatformAPI, LoggingPlatformAPI, NoPlatformAPI, StderrCapture, PLATFORM_API_METHOD_DOCS};
+use crate::tokens::{LineCounts, TOKEN_TYPES};
+
+mod ast;
+mod ast_query;
+mod ast_validation;
+mod diagnostics;
+mod encoding;
+mod hash;
+mod hints;
+mod imports;
+mod json_style;
+mod lang_args;
+mod logger;
+mod platform;
+mod test_skeleton;
+mod tokens;
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+enum StdinMode {
+    #[default]
+    Code,
+    File,
+}
+
+impl StdinMode {
+    fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "code" => Some(StdinMode::Code),
+            "file" => Some(StdinMode::File),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+enum ExecutionSummary {
+    Always,
+    #[default]
+    OnError,
+    Never,
+}
+
+impl ExecutionSummary {
+    fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "always" => Some(ExecutionSummary::Always),
+            "on-error" => Some(ExecutionSummary::OnError),
+            "never" => Some(ExecutionSummary::Never),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct ExecutionArgs {
+    print_translations: bool,
+    print_returned_value: bool,
+    print_return_type: bool,
+    warnings: bool,
+    check_assertions: bool,
+    print_platform_api_calls: bool,
+    source_encoding: SourceEncoding,
+    stdin_mode: Option<StdinMode>,
+    file_hash_algorithm: Option<HashAlgorithm>,
+    capture_stderr_file: Option<String>,
+    execution_log_file: Option<String>,
+    no_platform_api: bool,
+    execution_summary: ExecutionSummary,
+    wrap_diagnostics: bool,
+    diagnostics_width: Option<usize>,
+    list_lang_args: bool,
+    json_output: bool,
+    log_level: Option<log::LevelFilter>,
+    log_file: Option<String>,
+    lang_args: Option<Vec<Box<str>>>,
+    json_lang_args: Option<Vec<serde_json::Value>>,
+}
 
 fn main() -> ExitCode {
     let mut args = env::args();
@@ -14,31 +98,49 @@ fn main() -> ExitCode {
     let binary_name = args.next();
     let binary_name = binary_name.as_deref();
 
-    let args = args.collect::<Vec<String>>();
+    let mut args = args.collect::<Vec<String>>();
 
-    if args.is_empty() {
-        print_help(binary_name);
+    let mut no_stdin_auto = false;
+    let mut hint = false;
+    let mut json_style = JsonStyle::detect();
+    while let Some(arg) = args.first() {
+        match &**arg {
+            "--no-stdin-auto" => no_stdin_auto = true,
+            "--hint" => hint = true,
+            "--output-json-pretty" => json_style = JsonStyle::Pretty,
+            "--output-json-compact" => json_style = JsonStyle::Compact,
+            _ => break,
+        }
 
-        return ExitCode::SUCCESS;
+        args.remove(0);
     }
 
-    if !args[0].starts_with("-") || args[0] == "-e" || args[0].starts_with("--") || args[0].starts_with("-h") {
-        if args[0].starts_with("-h") {
-            print_help(binary_name);
-
-            return ExitCode::SUCCESS;
-        }
+    if args.is_empty() {
+        //Execute the code from standard input if it is piped into the CLI
+        if !no_stdin_auto && !io::stdin().is_terminal() {
+            if let Err(e) = logger::init_default() {
+                eprintln!("{e}");
 
-        if args[0].starts_with("--") {
-            if args[0] != "--help" {
-                eprintln!("Unknown COMMAND \"{}\"", args[0]);
+                return ExitCode::FAILURE;
             }
 
-            print_help(binary_name);
-
-            if args[0] != "--help" {
+            let Some(code) = read_stdin() else {
                 return ExitCode::FAILURE;
+            };
+
+            if !code.is_empty() {
+                return execute_lang_code(&String::from_utf8_lossy(&code), ExecutionArgs::default());
             }
+        }
+
+        print_help(binary_name);
+
+        return ExitCode::SUCCESS;
+    }
+
+    if !args[0].starts_with("-") || args[0] == "-" || args[0] == "-e" || args[0].starts_with("-h") {
+        if args[0].starts_with("-h") {
+            print_help(binary_name);
 
             return ExitCode::SUCCESS;
         }
@@ -53,24 +155,169 @@ fn main() -> ExitCode {
         }
 
         let execution_args_start_index = if lang_file_execution { 1 } else { 2 };
-        let mut print_translations = false;
-        let mut print_returned_value = false;
-        let mut warnings = false;
-        let mut lang_args = None;
+        let mut execution_args = ExecutionArgs::default();
+
+        let Some(raw_execution_args) = expand_execution_args_files(&args[execution_args_start_index..], binary_name) else {
+            return ExitCode::FAILURE;
+        };
 
-        for (i, arg) in args[execution_args_start_index..].iter().
+        let mut execution_args_iter = raw_execution_args.iter().
                 map(|arg| &**arg).
-                enumerate() {
+                enumerate();
+        while let Some((i, arg)) = execution_args_iter.next() {
             match arg {
-                "-printTranslations" => print_translations = true,
-                "-printReturnedValue" => print_returned_value = true,
-                "-warnings" => warnings = true,
+                "-printTranslations" => execution_args.print_translations = true,
+                "-printReturnedValue" => execution_args.print_returned_value = true,
+                "--print-return-type" => execution_args.print_return_type = true,
+                "-warnings" => execution_args.warnings = true,
+                "--check-assertions" => execution_args.check_assertions = true,
+                "--print-platform-api-calls" => execution_args.print_platform_api_calls = true,
+                "--no-platform-api" => execution_args.no_platform_api = true,
+                "--wrap-diagnostics" => execution_args.wrap_diagnostics = true,
+                "--width" => {
+                    let Some((_, width)) = execution_args_iter.next() else {
+                        eprintln!("N argument for \"--width\" is missing");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    let Some(width) = width.parse().ok().filter(|width| *width > 0) else {
+                        eprintln!("Invalid width \"{width}\" (Must be a positive integer)");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    execution_args.diagnostics_width = Some(width);
+                },
+                "--list-lang-args" => execution_args.list_lang_args = true,
+                "--format=text" => execution_args.json_output = false,
+                "--format=json" => execution_args.json_output = true,
+                _ if arg.starts_with("--encoding=") => {
+                    let Some(encoding) = parse_encoding_arg(arg, binary_name) else {
+                        return ExitCode::FAILURE;
+                    };
+
+                    execution_args.source_encoding = encoding;
+                },
+                _ if arg.starts_with("--stdin-mode=") => {
+                    let stdin_mode = &arg["--stdin-mode=".len()..];
+                    let Some(stdin_mode) = StdinMode::from_arg(stdin_mode) else {
+                        eprintln!("Invalid stdin mode \"{stdin_mode}\" (Must be \"code\" or \"file\")");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    execution_args.stdin_mode = Some(stdin_mode);
+                },
+                _ if arg.starts_with("--execution-summary=") => {
+                    let execution_summary = &arg["--execution-summary=".len()..];
+                    let Some(execution_summary) = ExecutionSummary::from_arg(execution_summary) else {
+                        eprintln!("Invalid execution summary mode \"{execution_summary}\" (Must be \"always\", \"on-error\", or \"never\")");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    execution_args.execution_summary = execution_summary;
+                },
+                _ if arg.starts_with("--log-level=") => {
+                    let log_level = &arg["--log-level=".len()..];
+                    let Some(log_level) = logger::level_filter_from_arg(log_level) else {
+                        eprintln!("Invalid log level \"{log_level}\" (Must be \"error\", \"warn\", \"info\", \"debug\", or \"trace\")");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    execution_args.log_level = Some(log_level);
+                },
+                "--print-file-hash" => {
+                    let Some((_, algorithm)) = execution_args_iter.next() else {
+                        eprintln!("ALGORITHM argument for \"--print-file-hash\" is missing");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    let Some(algorithm) = HashAlgorithm::from_arg(algorithm) else {
+                        eprintln!("Invalid hash algorithm \"{algorithm}\" (Must be \"SHA256\" or \"BLAKE3\")");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    execution_args.file_hash_algorithm = Some(algorithm);
+                },
+                "--capture-stderr-to" => {
+                    let Some((_, file)) = execution_args_iter.next() else {
+                        eprintln!("FILE argument for \"--capture-stderr-to\" is missing");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    execution_args.capture_stderr_file = Some(file.to_string());
+                },
+                "--execution-log" => {
+                    let Some((_, file)) = execution_args_iter.next() else {
+                        eprintln!("FILE argument for \"--execution-log\" is missing");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    execution_args.execution_log_file = Some(file.to_string());
+                },
+                "--log-file" => {
+                    let Some((_, log_file)) = execution_args_iter.next() else {
+                        eprintln!("FILE argument for \"--log-file\" is missing");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    execution_args.log_file = Some(log_file.to_string());
+                },
+                "--eval-args" => {
+                    let Some((_, json)) = execution_args_iter.next() else {
+                        eprintln!("JSON_ARRAY argument for \"--eval-args\" is missing");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    match lang_args::parse_json_lang_args(json) {
+                        Ok(json_lang_args) => execution_args.json_lang_args = Some(json_lang_args),
+                        Err(e) => {
+                            eprintln!("Invalid JSON_ARRAY argument for \"--eval-args\": {e}");
+
+                            return ExitCode::FAILURE;
+                        },
+                    }
+                },
                 "-langArgs" | "--" => {
-                    lang_args = Some(args[execution_args_start_index + i + 1..].iter().map(|str| Box::from(&**str)).collect());
+                    execution_args.lang_args = Some(raw_execution_args[i + 1..].iter().map(|str| Box::from(&**str)).collect());
                     break;
                 },
                 _ => {
                     eprintln!("Unknown EXECUTION_ARG \"{}\"", arg);
+                    if hint {
+                        hints::print_similar_arg_hint(arg, &hints::EXECUTION_ARGS);
+                    }
 
                     print_help(binary_name);
 
@@ -79,43 +326,322 @@ fn main() -> ExitCode {
             }
         }
 
-        return if lang_file_execution {
-            execute_lang_file(&args[0], print_translations, print_returned_value, warnings, lang_args)
+        if execution_args.lang_args.is_some() && execution_args.json_lang_args.is_some() {
+            eprintln!("\"--eval-args\" can not be used together with LANG_ARGs");
+
+            print_help(binary_name);
+
+            return ExitCode::FAILURE;
+        }
+
+        if execution_args.stdin_mode.is_some() && args[0] != "-" {
+            eprintln!("\"--stdin-mode\" can only be used with \"-\" as FILE");
+
+            print_help(binary_name);
+
+            return ExitCode::FAILURE;
+        }
+
+        if execution_args.list_lang_args {
+            print_lang_args(&execution_args, json_style);
+
+            return ExitCode::SUCCESS;
+        }
+
+        if let Err(e) = logger::init(
+            execution_args.log_level.unwrap_or(logger::DEFAULT_LEVEL),
+            execution_args.log_file.as_deref(),
+        ) {
+            eprintln!("{e}");
+
+            return ExitCode::FAILURE;
+        }
+
+        return if args[0] == "-" {
+            execute_stdin(execution_args)
+        }else if lang_file_execution {
+            if hint {
+                hints::print_lang_file_hint(&args[0]);
+            }
+
+            execute_lang_file(&args[0], execution_args)
         }else {
-            execute_lang_code(&args[1], print_translations, print_returned_value, warnings, lang_args)
+            execute_lang_code(&args[1], execution_args)
         };
     }
 
+    if let Err(e) = logger::init_default() {
+        eprintln!("{e}");
+
+        return ExitCode::FAILURE;
+    }
+
     match &*args[0] {
-        "-printTokens" => {
+        "--help" => {
+            print_help(binary_name);
+
+            ExitCode::SUCCESS
+        },
+
+        "--count-lines" => {
+            if args.len() < 2 {
+                eprintln!("\"--count-lines\" requires at least one file argument");
+
+                print_help(binary_name);
+
+                return ExitCode::FAILURE;
+            }
+
+            let mut total_line_counts = LineCounts::default();
+            for file in &args[1..] {
+                let Some(code) = read_file(file) else {
+                    return ExitCode::FAILURE;
+                };
+
+                let line_counts = LineCounts::count(&String::from_utf8_lossy(&code));
+                print_line_counts(file, &line_counts);
+
+                total_line_counts += line_counts;
+            }
+
+            if args.len() > 2 {
+                print_line_counts("Total", &total_line_counts);
+            }
+
+            ExitCode::SUCCESS
+        },
+
+        "--check-file-exists" => {
             if args.len() != 2 {
-                eprintln!("\"printTokens\" requires exactly one file argument");
+                eprintln!("\"--check-file-exists\" requires exactly one file argument");
+                if hint && args.len() == 1 {
+                    hints::print_missing_file_hint(binary_name, "--check-file-exists FILE");
+                }
 
                 print_help(binary_name);
 
                 return ExitCode::FAILURE;
             }
 
-            let file = File::open(&args[1]);
-            let mut file = match file {
-                Ok(file) => file,
-                Err(e) => {
-                    eprintln!("FILE can not be read {e}");
+            check_imported_files_exist(&args[1])
+        },
+
+        "--check-imports-only" => {
+            if args.len() != 2 {
+                eprintln!("\"--check-imports-only\" requires exactly one file argument");
+                if hint && args.len() == 1 {
+                    hints::print_missing_file_hint(binary_name, "--check-imports-only FILE");
+                }
+
+                print_help(binary_name);
+
+                return ExitCode::FAILURE;
+            }
+
+            check_imported_files_resolvable(&args[1])
+        },
+
+        "--generate-test-skeleton" => {
+            let mut output_file = None;
+            let mut files = Vec::new();
+
+            let mut skeleton_args = args[1..].iter();
+            while let Some(arg) = skeleton_args.next() {
+                if arg == "--output" {
+                    let Some(file) = skeleton_args.next() else {
+                        eprintln!("FILE argument for \"--output\" is missing");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    output_file = Some(file);
+                }else if arg.starts_with("--") {
+                    eprintln!("Unknown option \"{arg}\" for \"--generate-test-skeleton\"");
+                    if hint {
+                        hints::print_similar_arg_hint(arg, &["--output"]);
+                    }
+
+                    print_help(binary_name);
 
                     return ExitCode::FAILURE;
-                },
+                }else {
+                    files.push(arg);
+                }
+            }
+
+            if files.len() != 1 {
+                eprintln!("\"--generate-test-skeleton\" requires exactly one file argument");
+                if hint && files.is_empty() {
+                    hints::print_missing_file_hint(binary_name, "--generate-test-skeleton FILE [--output FILE]");
+                }
+
+                print_help(binary_name);
+
+                return ExitCode::FAILURE;
+            }
+
+            let Some(code) = read_file(files[0]) else {
+                return ExitCode::FAILURE;
             };
 
-            let mut code = Vec::new();
-            let ret = file.read_to_end(&mut code);
-            if let Err(e) = ret {
-                eprintln!("FILE can not be read {e}");
+            let ast = Parser::new().parse_lines(String::from_utf8_lossy(&code)).unwrap();
+
+            let function_names = test_skeleton::find_public_function_names(&ast);
+            if function_names.is_empty() {
+                eprintln!("No public functions were found in \"{}\"", files[0]);
+            }
+
+            //The test skeleton is written to standard output if there is no output file
+            let output_dir = output_file.map_or_else(|| PathBuf::from("."), |output_file| imports::lang_path_of(Path::new(output_file)));
+            let linked_file = test_skeleton::linked_file_path(Path::new(files[0]), &output_dir);
+            let test_skeleton = test_skeleton::generate_test_skeleton(&linked_file, &function_names);
+
+            let Some(output_file) = output_file else {
+                print!("{test_skeleton}");
+
+                return ExitCode::SUCCESS;
+            };
+
+            //Existing test files are never overwritten
+            let result = File::create_new(output_file).
+                    and_then(|mut file| file.write_all(test_skeleton.as_bytes()));
+            if let Err(e) = result {
+                eprintln!("Test skeleton file \"{output_file}\" can not be created: {e}");
 
+                return ExitCode::FAILURE;
+            }
+
+            ExitCode::SUCCESS
+        },
+
+        "--introspect-api" => {
+            let mut json = false;
+            for arg in &args[1..] {
+                match &**arg {
+                    "--format=text" => json = false,
+                    "--format=json" => json = true,
+                    _ => {
+                        eprintln!("Unknown option \"{arg}\" for \"--introspect-api\"");
+                        if hint {
+                            hints::print_similar_arg_hint(arg, &["--format"]);
+                        }
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    },
+                }
+            }
+
+            print_platform_api_docs(json, json_style);
+
+            ExitCode::SUCCESS
+        },
+
+        "--print-token-types" => {
+            for token_type in TOKEN_TYPES {
+                println!("{token_type}");
+            }
+
+            ExitCode::SUCCESS
+        },
+
+        "--print-ast-node-types" => {
+            for node_type_name in ast::NODE_TYPE_NAMES {
+                println!("{node_type_name}");
+            }
+
+            ExitCode::SUCCESS
+        },
+
+        "-printTokens" => {
+            let mut count = false;
+            let mut strip_comments = false;
+            let mut strip_whitespace = false;
+            let mut print_token_span = false;
+            let mut hash_algorithm = None;
+            let mut source_encoding = SourceEncoding::Utf8;
+            let mut files = Vec::new();
+
+            for arg in &args[1..] {
+                if arg == "--count" {
+                    count = true;
+                }else if arg == "--strip-comments" {
+                    strip_comments = true;
+                }else if arg == "--strip-whitespace" {
+                    strip_whitespace = true;
+                }else if arg == "--print-token-span" {
+                    print_token_span = true;
+                }else if arg == "--hash-only" || arg.starts_with("--hash-only=") {
+                    let Some(algorithm) = parse_hash_only_arg(arg, binary_name) else {
+                        return ExitCode::FAILURE;
+                    };
+
+                    hash_algorithm = Some(algorithm);
+                }else if arg.starts_with("--encoding=") {
+                    let Some(encoding) = parse_encoding_arg(arg, binary_name) else {
+                        return ExitCode::FAILURE;
+                    };
+
+                    source_encoding = encoding;
+                }else if arg.starts_with("--") {
+                    eprintln!("Unknown option \"{arg}\" for \"printTokens\"");
+                    if hint {
+                        hints::print_similar_arg_hint(arg, &hints::TOKEN_ARGS);
+                    }
+
+                    print_help(binary_name);
+
+                    return ExitCode::FAILURE;
+                }else {
+                    files.push(arg);
+                }
+            }
+
+            if files.len() != 1 {
+                eprintln!("\"printTokens\" requires exactly one file argument");
+                if hint && files.is_empty() {
+                    hints::print_missing_file_hint(binary_name, "-printTokens [TOKEN_ARGs]... FILE");
+                }
+
+                print_help(binary_name);
+
+                return ExitCode::FAILURE;
+            }
+
+            let Some(code) = read_file(files[0]) else {
                 return ExitCode::FAILURE;
             };
 
-            println!("{}", Lexer::new().read_tokens(String::from_utf8_lossy(&code)).iter().
-                    map(ToString::to_string).
+            let mut tokens = Lexer::new().read_tokens(source_encoding.decode(&code));
+
+            if let Some(hash_algorithm) = hash_algorithm {
+                println!("{}", hash_algorithm.hash_hex(tokens::canonical_token_stream(tokens).as_bytes()));
+
+                return ExitCode::SUCCESS;
+            }
+
+            if strip_comments {
+                tokens = tokens::strip_comments(tokens);
+            }
+            if strip_whitespace {
+                tokens = tokens::strip_whitespace(tokens);
+            }
+
+            if count {
+                println!("{}", tokens.len());
+
+                return ExitCode::SUCCESS;
+            }
+
+            println!("{}", tokens.iter().
+                    map(|token| if print_token_span {
+                        tokens::format_token_with_span(token)
+                    }else {
+                        token.to_string()
+                    }).
                     collect::<Vec<_>>().
                     join("\n"));
 
@@ -123,39 +649,182 @@ fn main() -> ExitCode {
         },
 
         "-printAST" => {
-            if args.len() != 2 {
+            let mut ast_traversal = AstTraversal::DepthFirst;
+            let mut ast_format = AstFormat::Text;
+            let mut types_only = false;
+            let mut leaf_count = false;
+            let mut ast_validate = false;
+            let mut height_only = false;
+            let mut statistics_only = false;
+            let mut hash_algorithm = None;
+            let mut compact_identical_subtrees = false;
+            let mut ast_query = None;
+            let mut source_encoding = SourceEncoding::Utf8;
+            let mut files = Vec::new();
+
+            let mut ast_args = args[1..].iter();
+            while let Some(arg) = ast_args.next() {
+                if let Some(traversal) = arg.strip_prefix("--ast-traversal=") {
+                    let Some(traversal) = AstTraversal::from_arg(traversal) else {
+                        eprintln!("Invalid AST traversal order \"{traversal}\" (Must be \"dfs\" or \"bfs\")");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    ast_traversal = traversal;
+                }else if let Some(format) = arg.strip_prefix("--format=") {
+                    let Some(format) = AstFormat::from_arg(format) else {
+                        eprintln!("Invalid AST format \"{format}\" (Must be \"text\" or \"dot\")");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    ast_format = format;
+                }else if arg == "--types-only" {
+                    types_only = true;
+                }else if arg == "--leaf-count" {
+                    leaf_count = true;
+                }else if arg == "--hash-only" || arg.starts_with("--hash-only=") {
+                    let Some(algorithm) = parse_hash_only_arg(arg, binary_name) else {
+                        return ExitCode::FAILURE;
+                    };
+
+                    hash_algorithm = Some(algorithm);
+                }else if arg == "--height-only" {
+                    height_only = true;
+                }else if arg == "--statistics-only" {
+                    statistics_only = true;
+                }else if arg == "--ast-validate" {
+                    ast_validate = true;
+                }else if arg == "--compact-identical-subtrees" {
+                    compact_identical_subtrees = true;
+                }else if arg == "--ast-query" {
+                    let Some(query) = ast_args.next() else {
+                        eprintln!("QUERY argument for \"--ast-query\" is missing");
+
+                        print_help(binary_name);
+
+                        return ExitCode::FAILURE;
+                    };
+
+                    match AstQuery::parse(query) {
+                        Ok(query) => ast_query = Some(query),
+                        Err(e) => {
+                            eprintln!("Invalid QUERY argument for \"--ast-query\": {e}");
+
+                            return ExitCode::FAILURE;
+                        },
+                    }
+                }else if arg.starts_with("--encoding=") {
+                    let Some(encoding) = parse_encoding_arg(arg, binary_name) else {
+                        return ExitCode::FAILURE;
+                    };
+
+                    source_encoding = encoding;
+                }else if arg.starts_with("--") {
+                    eprintln!("Unknown option \"{arg}\" for \"printAST\"");
+                    if hint {
+                        hints::print_similar_arg_hint(arg, &hints::AST_ARGS);
+                    }
+
+                    print_help(binary_name);
+
+                    return ExitCode::FAILURE;
+                }else {
+                    files.push(arg);
+                }
+            }
+
+            if files.len() != 1 {
                 eprintln!("\"printAST\" requires exactly one file argument");
+                if hint && files.is_empty() {
+                    hints::print_missing_file_hint(binary_name, "-printAST [AST_ARGs]... FILE");
+                }
 
                 print_help(binary_name);
 
                 return ExitCode::FAILURE;
             }
 
-            let file = File::open(&args[1]);
-            let mut file = match file {
-                Ok(file) => file,
-                Err(e) => {
-                    eprintln!("FILE can not be read {e}");
-
-                    return ExitCode::FAILURE;
-                },
+            let Some(code) = read_file(files[0]) else {
+                return ExitCode::FAILURE;
             };
 
-            let mut code = Vec::new();
-            let ret = file.read_to_end(&mut code);
-            if let Err(e) = ret {
-                eprintln!("FILE can not be read {e}");
+            let ast = Parser::new().parse_lines(source_encoding.decode(&code)).unwrap();
+
+            if ast_validate {
+                let violations = ast_validation::validate(&ast);
+                if violations.is_empty() {
+                    println!("AST is valid");
+
+                    return ExitCode::SUCCESS;
+                }
+
+                for violation in &violations {
+                    println!("{violation}");
+                }
+                eprintln!("AST has {} violation(s)", violations.len());
 
                 return ExitCode::FAILURE;
-            };
+            }
+
+            if let Some(ast_query) = ast_query {
+                for node in ast_query.find(&ast) {
+                    println!("{node}");
+                }
+
+                return ExitCode::SUCCESS;
+            }
+
+            if leaf_count {
+                println!("{}", ast::count_leaf_nodes(&ast));
+
+                return ExitCode::SUCCESS;
+            }
+
+            if let Some(hash_algorithm) = hash_algorithm {
+                println!("{}", hash_algorithm.hash_hex(ast::canonical_ast(&ast).as_bytes()));
+
+                return ExitCode::SUCCESS;
+            }
+
+            if height_only {
+                println!("{}", ast::ast_height(&ast));
+
+                return ExitCode::SUCCESS;
+            }
+
+            if statistics_only {
+                ast::print_ast_statistics(&ast);
+
+                return ExitCode::SUCCESS;
+            }
 
-            println!("{}", Parser::new().parse_lines(String::from_utf8_lossy(&code)).unwrap());
+            if ast_format == AstFormat::Dot {
+                ast::print_ast_dot(&ast, types_only);
+
+                return ExitCode::SUCCESS;
+            }
+
+            match ast_traversal {
+                AstTraversal::DepthFirst if compact_identical_subtrees => ast::print_ast_compact_identical_subtrees(&ast, types_only),
+                AstTraversal::DepthFirst if types_only => ast::print_ast_types_depth_first(&ast),
+                AstTraversal::DepthFirst => println!("{ast}"),
+                AstTraversal::BreadthFirst => ast::print_ast_breadth_first(&ast, types_only),
+            }
 
             ExitCode::SUCCESS
         },
 
         _ => {
             eprintln!("Unknown COMMAND \"{}\"", args[0]);
+            if hint {
+                hints::print_unknown_command_hint(&args[0]);
+            }
 
             print_help(binary_name);
 
@@ -164,6 +833,121 @@ fn main() -> ExitCode {
     }
 }
 
+/// Checks that all files imported by `lang_file` (And transitively by the imported Lang files) exist
+fn check_imported_files_exist(lang_file: &str) -> ExitCode {
+    let Some(code) = read_file(lang_file) else {
+        return ExitCode::FAILURE;
+    };
+
+    let missing_file_count = imports::walk_imports(Path::new(lang_file), code, |import| {
+        if !import.path.is_file() {
+            return Err("Missing file".to_string());
+        }
+
+        if !import.is_lang_file() {
+            return Ok(None);
+        }
+
+        fs::read(&import.path).
+                map(Some).
+                map_err(|e| format!("Unreadable file ({e})"))
+    });
+
+    if missing_file_count > 0 {
+        eprintln!("{missing_file_count} imported file(s) are missing or can not be read");
+
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Checks that all files imported by `lang_file` (And transitively by the imported Lang files) can be read
+/// and that imported Lang files can be parsed without parsing errors
+fn check_imported_files_resolvable(lang_file: &str) -> ExitCode {
+    let Some(code) = read_file(lang_file) else {
+        return ExitCode::FAILURE;
+    };
+
+    let unresolvable_import_count = imports::walk_imports(Path::new(lang_file), code, |import| {
+        let import_code = fs::read(&import.path).map_err(|e| format!("Unresolvable import ({e})"))?;
+
+        if !import.is_lang_file() {
+            return Ok(None);
+        }
+
+        let import_ast = Parser::new().parse_lines(String::from_utf8_lossy(&import_code)).unwrap();
+        if let Some(parsing_error) = ast::find_parsing_error(&import_ast) {
+            return Err(format!(
+                "Unparsable import ({} at {})",
+                ast::node_value(parsing_error).unwrap_or_default(), parsing_error.pos().to_compact_string(),
+            ));
+        }
+
+        Ok(Some(import_code))
+    });
+
+    if unresolvable_import_count > 0 {
+        eprintln!("{unresolvable_import_count} import(s) can not be resolved");
+
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn print_lang_args(execution_args: &ExecutionArgs, json_style: JsonStyle) {
+    if let Some(json_lang_args) = &execution_args.json_lang_args {
+        println!("{}", json_style.format(&serde_json::Value::from(json_lang_args.clone())));
+
+        return;
+    }
+
+    let lang_args = execution_args.lang_args.as_deref().unwrap_or_default();
+    if execution_args.json_output {
+        println!("{}", json_style.format(&serde_json::json!(lang_args)));
+    }else {
+        println!("{lang_args:?}");
+    }
+}
+
+fn print_platform_api_docs(json: bool, json_style: JsonStyle) {
+    if json {
+        let method_docs = PLATFORM_API_METHOD_DOCS.iter().
+                map(|method_doc| serde_json::json!({
+                    "name": method_doc.name,
+                    "signature": method_doc.signature,
+                    "description": method_doc.description,
+                    "hasDefaultImplementation": method_doc.has_default_implementation,
+                })).
+                collect::<Vec<_>>();
+
+        println!("{}", json_style.format(&serde_json::json!({
+            "trait": "PlatformAPI",
+            "methods": method_docs,
+        })));
+
+        return;
+    }
+
+    println!("trait PlatformAPI");
+    for method_doc in &PLATFORM_API_METHOD_DOCS {
+        println!();
+        println!("    {}", method_doc.signature);
+        println!("        {}", method_doc.description);
+        if method_doc.has_default_implementation {
+            println!("        (Has a default implementation)");
+        }
+    }
+}
+
+fn print_line_counts(name: &str, line_counts: &LineCounts) {
+    println!(
+        "{name}: {} lines ({} code, {} blank, {} comment)",
+        line_counts.total, line_counts.code, line_counts.blank, line_counts.comment,
+    );
+}
+
 fn print_help(binary_name: Option<&str>) {
     let binary_name = binary_name.unwrap_or("lang");
 
@@ -173,15 +957,61 @@ fn print_help(binary_name: Option<&str>) {
     println!("{}", "=".repeat(header_str.len()));
     println!("Interprets Lang code & files");
     println!();
-    println!("Usage: {binary_name} COMMAND [ARGs]... | {binary_name} -e CODE [EXECUTION_ARGs]... [LANG_ARGs]... | {binary_name} FILE [EXECUTION_ARGs]... [LANG_ARGs]...");
+    println!("Usage: {binary_name} [GLOBAL_OPTIONs]... COMMAND [ARGs]... | {binary_name} [GLOBAL_OPTIONs]... -e CODE [EXECUTION_ARGs]... [LANG_ARGs]... | {binary_name} [GLOBAL_OPTIONs]... FILE [EXECUTION_ARGs]... [LANG_ARGs]...");
+    println!();
+    println!("If no arguments are provided and standard input is not a terminal, the code from standard input is executed");
+    println!("If FILE is \"-\", standard input is read as Lang code or as the path of the Lang file (See \"--stdin-mode\")");
+    println!("GLOBAL_OPTIONs apply to all COMMANDs, to CODE, and to FILE and must be placed before them");
     println!();
     println!("COMMANDs");
     println!("--------");
-    println!("    -printAST FILE                   Prints the AST of a Lang file to standard output");
-    println!("    -printTokens FILE                Prints the tokens of a Lang file to standard output");
+    println!("    -printAST [AST_ARGs]... FILE     Prints the AST of a Lang file to standard output");
+    println!("    -printTokens [TOKEN_ARGs]... FILE");
+    println!("                                      Prints the tokens of a Lang file to standard output");
+    println!("    --check-file-exists FILE          Checks if all files imported by a Lang file (And transitively by the imported files) exist without executing it");
+    println!("    --check-imports-only FILE         Checks if all files imported by a Lang file (And transitively by the imported files) can be read and parsed without executing it");
+    println!("    --generate-test-skeleton FILE [--output FILE]");
+    println!("                                      Prints (Or writes to a new file) a langTest file with a stub test function for every public function of a Lang file");
+    println!("    --introspect-api [--format=text|json]");
+    println!("                                      Prints the documentation of all PlatformAPI methods (For custom PlatformAPI implementations)");
+    println!("    --print-token-types               Prints the names of all token types (As used by \"-printTokens\")");
+    println!("    --print-ast-node-types            Prints the names of all AST node types (As used by \"-printAST --types-only\" and \"--ast-query\")");
+    println!("    --count-lines FILE...             Prints the number of total, code, blank, and comment-only lines of Lang files (And the total if multiple files are provided)");
     println!();
     println!("    -h, --help                        Prints this help page");
     println!();
+    println!("GLOBAL_OPTIONs");
+    println!("--------------");
+    println!("    --no-stdin-auto                   Disables the execution of code from standard input if no other arguments are provided");
+    println!("    --output-json-pretty              Prints JSON output (e.g. of \"--format=json\") with an indentation of 4 spaces (Default if standard output is a terminal)");
+    println!("    --output-json-compact             Prints JSON output (e.g. of \"--format=json\") without any whitespace (Default if standard output is not a terminal)");
+    println!("    --hint                            Prints hints for common mistakes (e.g. \"Did you mean ...?\" for misspelled arguments) alongside error messages");
+    println!();
+    println!("TOKEN_ARGs");
+    println!("----------");
+    println!("    --count                           Prints only the total number of tokens");
+    println!("    --strip-comments                  Removes all comment tokens");
+    println!("    --strip-whitespace                Removes all whitespace and end of line tokens");
+    println!("    --hash-only[=SHA256|BLAKE3]       Prints only the hash of the tokens without comments, indentation, and positions (Default: SHA256)");
+    println!("    --print-token-span                Prints the tokens with their start and end position: [LINE:COLUMN..LINE:COLUMN] TYPE \"VALUE\"");
+    println!("    --encoding=utf-8|detect           Sets the encoding of FILE (\"detect\": Detects the encoding and falls back to UTF-8 if the detection is not confident enough)");
+    println!();
+    println!("AST_ARGs");
+    println!("--------");
+    println!("    --ast-traversal=dfs|bfs           Sets the order in which AST nodes are printed (\"dfs\": Depth-first [Default], \"bfs\": Breadth-first grouped by depth)");
+    println!("    --format=text|dot                 Sets the output format (\"text\": Text tree [Default], \"dot\": Graphviz DOT graph, e.g. for \"dot -Tpng -o ast.png\")");
+    println!("    --types-only                      Prints only the node type names without positions and values");
+    println!("    --leaf-count                      Prints only the number of leaf nodes (Nodes without children)");
+    println!("    --hash-only[=SHA256|BLAKE3]       Prints only the hash of the AST without positions (Default: SHA256)");
+    println!("    --height-only                     Prints only the height of the AST (The maximum depth of all nodes, the top level nodes have a depth of 0)");
+    println!("    --statistics-only                 Prints only the height, the node count, the leaf node count, and the most common node type of the AST");
+    println!("    --ast-validate                    Checks the structural invariants of the AST (e.g. every assignment has two child nodes) and prints all violations");
+    println!("    --compact-identical-subtrees      Prints repeated identical subtrees as \"<same as node N>\" (Depth-first traversal only)");
+    println!("    --ast-query QUERY                 Prints only the subtrees matching the XPath-like QUERY");
+    println!("                                      \"//TYPE\": Descendants of TYPE, \"/TYPE\": Direct children of TYPE, \"*\": Any TYPE,");
+    println!("                                      \"TYPE@value=VALUE\" or \"TYPE@line=LINE\": Nodes of TYPE with a matching attribute (e.g. \"//FunctionCall@value=fn.println\")");
+    println!("    --encoding=utf-8|detect           Sets the encoding of FILE (\"detect\": Detects the encoding and falls back to UTF-8 if the detection is not confident enough)");
+    println!();
     println!("IN-LINE CODE");
     println!("------------");
     println!("    -e CODE                           Executes CODE in the OS shell");
@@ -190,41 +1020,134 @@ fn print_help(binary_name: Option<&str>) {
     println!("--------------");
     println!("    -printTranslations                Prints all Translations after the execution of the Lang file finished to standard output");
     println!("    -printReturnedValue               Prints the returned or thrown value of the Lang file if any");
+    println!("    --print-return-type               Prints the data type of the returned or thrown value of the Lang file (\"unknown\" if there is none)");
     println!("    -warnings                         Enables the output of warnings which occur");
+    println!("    --check-assertions                Enables the langTest assertion functions (e.g. \"func.testAssertEquals\") and prints the results of all assertions after the execution");
+    println!("    --print-platform-api-calls        Prints every call of a PlatformAPI method including its arguments and result to standard error");
+    println!("    --encoding=utf-8|detect           Sets the encoding of the Lang file (\"detect\": Detects the encoding and falls back to UTF-8 if the detection is not confident enough)");
+    println!("    --stdin-mode=code|file            Sets how standard input is interpreted if FILE is \"-\" (\"code\": Lang code [Default], \"file\": Path of the Lang file)");
+    println!("    --no-platform-api                 Executes without side effects (All output is discarded, files can not be read, and input dialogs return an empty text)");
+    println!("    --wrap-diagnostics                Word-wraps the error output of the interpreter (e.g. warnings and error messages) at \"--width\" characters");
+    println!("    --width N                         Sets the width for \"--wrap-diagnostics\" (Default: Width of the terminal from $COLUMNS or 80)");
+    println!("    --capture-stderr-to FILE          Writes the error output of the interpreter (e.g. warnings and error messages) to FILE instead of standard error");
+    println!("    --execution-log FILE              Writes every PlatformAPI call (e.g. output and file access) as JSON object to the JSON Lines file FILE");
+    println!("    --execution-summary=always|on-error|never");
+    println!("                                      Prints a summary line with the result and execution time to standard error (Default: \"on-error\")");
+    println!("                                      (The line of the error is only reported for uncaught throw statements, not for errors of $LANG_ERRNO)");
+    println!("    --list-lang-args                  Prints the Lang args which would be passed to the interpreter without executing anything");
+    println!("    --format=text|json                Sets the output format of \"--list-lang-args\" (\"text\" [Default], \"json\": JSON array)");
+    println!("    --print-file-hash SHA256|BLAKE3   Prints the hash of the Lang file (Or of CODE) to standard error before the execution");
+    println!("    --log-level=error|warn|info|debug|trace");
+    println!("                                      Sets the verbosity of the log output of the CLI (Default: \"warn\")");
+    println!("    --log-file FILE                   Writes the log output to FILE instead of standard error");
+    println!("    --eval-args JSON_ARRAY            Passes the elements of JSON_ARRAY as typed Lang args (null, numbers, texts, and arrays; booleans are converted to 1 or 0)");
+    println!("    --execution-args-file FILE        Reads additional EXECUTION_ARGs from FILE (One per line, empty lines and lines starting with \"#\" are ignored)");
     println!("    -langArgs                         Indicates the start of the Lang args arguments (Everything after this argument will be interpreted as Lang args)");
     println!("    --                                Alias for \"-langArgs\"");
 }
 
-fn execute_lang_code(lang_code: &str, print_translations: bool, print_returned_value: bool, warnings: bool, lang_args: Option<Vec<Box<str>>>) -> ExitCode {
-    let current_dir = env::current_dir().unwrap();
+fn parse_encoding_arg(arg: &str, binary_name: Option<&str>) -> Option<SourceEncoding> {
+    let encoding = &arg["--encoding=".len()..];
 
-    let mut interpreter = Interpreter::new(
-        current_dir.to_str().unwrap(),
-        None,
-        None,
-        Box::new(DefaultPlatformAPI::new()),
-        lang_args,
-    );
+    let source_encoding = SourceEncoding::from_arg(encoding);
+    if source_encoding.is_none() {
+        eprintln!("Invalid encoding \"{encoding}\" (Must be \"utf-8\" or \"detect\")");
 
-    if warnings {
-        lii::set_error_output_flag(&mut interpreter, ErrorOutputFlag::All);
+        print_help(binary_name);
     }
 
-    interpreter.interpret_lines(lang_code);
+    source_encoding
+}
 
-    print_post_execution_output(&mut interpreter, print_translations, print_returned_value);
+/// Replaces every "--execution-args-file FILE" (Before the start of the Lang args) with the execution args of FILE
+///
+/// FILE contains one execution arg per line, empty lines and lines starting with "#" are ignored
+fn expand_execution_args_files(args: &[String], binary_name: Option<&str>) -> Option<Vec<String>> {
+    let mut expanded_args = Vec::with_capacity(args.len());
 
-    ExitCode::SUCCESS
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match &**arg {
+            "--execution-args-file" => {
+                let Some(file) = args_iter.next() else {
+                    eprintln!("FILE argument for \"--execution-args-file\" is missing");
+
+                    print_help(binary_name);
+
+                    return None;
+                };
+
+                //The logger is not initialized yet, because the execution args file can contain logger EXECUTION_ARGs
+                let content = match fs::read(file) {
+                    Ok(content) => content,
+                    Err(e) => {
+                        eprintln!("FILE can not be read {e}");
+
+                        return None;
+                    },
+                };
+                for line in String::from_utf8_lossy(&content).lines() {
+                    let line = line.trim();
+                    if line.is_empty() || line.starts_with('#') {
+                        continue;
+                    }
+
+                    if line == "--execution-args-file" || line == "-langArgs" || line == "--" {
+                        eprintln!("\"{line}\" can not be used inside of the execution args file \"{file}\"");
+
+                        return None;
+                    }
+
+                    expanded_args.push(line.to_string());
+                }
+            },
+
+            "-langArgs" | "--" => {
+                expanded_args.push(arg.clone());
+                expanded_args.extend(args_iter.by_ref().cloned());
+            },
+
+            _ => expanded_args.push(arg.clone()),
+        }
+    }
+
+    Some(expanded_args)
 }
 
-fn execute_lang_file(lang_file: &str, print_translations: bool, print_returned_value: bool, warnings: bool, lang_args: Option<Vec<Box<str>>>) -> ExitCode {
-    let file = File::open(lang_file);
+fn parse_hash_only_arg(arg: &str, binary_name: Option<&str>) -> Option<HashAlgorithm> {
+    let Some(algorithm) = arg.strip_prefix("--hash-only=") else {
+        return Some(HashAlgorithm::Sha256);
+    };
+
+    let hash_algorithm = HashAlgorithm::from_arg(algorithm);
+    if hash_algorithm.is_none() {
+        eprintln!("Invalid hash algorithm \"{algorithm}\" (Must be \"SHA256\" or \"BLAKE3\")");
+
+        print_help(binary_name);
+    }
+
+    hash_algorithm
+}
+
+fn read_stdin() -> Option<Vec<u8>> {
+    let mut input = Vec::new();
+    if let Err(e) = io::stdin().read_to_end(&mut input) {
+        eprintln!("Standard input can not be read {e}");
+
+        return None;
+    }
+
+    Some(input)
+}
+
+fn read_file(file: &str) -> Option<Vec<u8>> {
+    let file = File::open(file);
     let mut file = match file {
         Ok(file) => file,
         Err(e) => {
             eprintln!("FILE can not be read {e}");
 
-            return ExitCode::FAILURE;
+            return None;
         },
     };
 
@@ -233,36 +1156,247 @@ fn execute_lang_file(lang_file: &str, print_translations: bool, print_returned_v
     if let Err(e) = ret {
         eprintln!("FILE can not be read {e}");
 
+        return None;
+    };
+
+    Some(code)
+}
+
+fn create_platform_api(execution_args: &ExecutionArgs) -> Option<Box<dyn PlatformAPI>> {
+    let mut platform_api: Box<dyn PlatformAPI> = if execution_args.no_platform_api {
+        Box::new(NoPlatformAPI::new())
+    }else {
+        Box::new(DefaultPlatformAPI::new())
+    };
+
+    if let Some(capture_stderr_file) = &execution_args.capture_stderr_file {
+        let file = match File::create(capture_stderr_file) {
+            Ok(file) => file,
+            Err(e) => {
+                eprintln!("File \"{capture_stderr_file}\" for \"--capture-stderr-to\" can not be created {e}");
+
+                return None;
+            },
+        };
+
+        platform_api = Box::new(ErrorOutputPlatformAPI::new(platform_api, StderrCapture::new(file)));
+    }
+
+    if let Some(execution_log_file) = &execution_args.execution_log_file {
+        let file = match File::create(execution_log_file) {
+            Ok(file) => file,
+            Err(e) => {
+                eprintln!("File \"{execution_log_file}\" for \"--execution-log\" can not be created {e}");
+
+                return None;
+            },
+        };
+
+        platform_api = Box::new(LoggingPlatformAPI::new(platform_api, file));
+    }
+
+    if execution_args.wrap_diagnostics {
+        let width = execution_args.diagnostics_width.unwrap_or_else(diagnostics::terminal_width);
+
+        platform_api = Box::new(ErrorOutputPlatformAPI::new(platform_api, DiagnosticWrap::new(width)));
+    }
+
+    if execution_args.print_platform_api_calls {
+        platform_api = Box::new(DebugPlatformAPI::new(platform_api));
+    }
+
+    Some(platform_api)
+}
+
+/// Returns the lang args with which the interpreter is created
+///
+/// If typed lang args are used, an empty lang args array is created which is replaced in [init_interpreter].
+/// The interpreter only passes the `&LANG_ARGS` of the constructor to function scopes, the default `&LANG_ARGS` lang var is not passed.
+fn interpreter_lang_args(execution_args: &ExecutionArgs) -> Option<Vec<Box<str>>> {
+    if execution_args.json_lang_args.is_some() {
+        return Some(Vec::new());
+    }
+
+    execution_args.lang_args.clone()
+}
+
+fn init_interpreter(interpreter: &mut Interpreter, execution_args: &ExecutionArgs) {
+    log::debug!("Initializing interpreter with {execution_args:?}");
+
+    if execution_args.warnings {
+        lii::set_error_output_flag(interpreter, ErrorOutputFlag::All);
+    }
+
+    if let Some(json_lang_args) = &execution_args.json_lang_args
+        && let Err(e) = lang_args::set_json_lang_args(interpreter, json_lang_args) {
+            log::error!("Lang args could not be set: {e}");
+        }
+
+    if execution_args.check_assertions {
+        //Enables the langTest assertion functions (e.g. "func.testAssertEquals") which are ignored otherwise
+        lii::exec(interpreter, "lang.test = 1");
+        lii::reset_parser_positional_vars(interpreter);
+    }
+}
+
+fn execute_lang_code(lang_code: &str, execution_args: ExecutionArgs) -> ExitCode {
+    let current_dir = env::current_dir().unwrap();
+
+    if let Some(algorithm) = execution_args.file_hash_algorithm {
+        eprintln!("{}: {}", algorithm.name(), algorithm.hash_hex(lang_code.as_bytes()));
+    }
+
+    log::debug!("Creating interpreter with lang path \"{}\"", current_dir.display());
+
+    let Some(lang_platform_api) = create_platform_api(&execution_args) else {
         return ExitCode::FAILURE;
     };
 
-    let lang_platform_api = DefaultPlatformAPI::new();
+    let mut interpreter = Interpreter::new(
+        current_dir.to_str().unwrap(),
+        None,
+        None,
+        lang_platform_api,
+        interpreter_lang_args(&execution_args),
+    );
+
+    init_interpreter(&mut interpreter, &execution_args);
+
+    log::info!("Executing in-line code ({} bytes)", lang_code.len());
+    let start = Instant::now();
+    interpreter.interpret_lines(lang_code);
+    let execution_time = start.elapsed();
+    log::info!("Execution finished");
+
+    let execution_failure = get_execution_failure(&interpreter);
+
+    print_post_execution_output(&mut interpreter, &execution_args);
+
+    print_execution_summary("<shell>", execution_failure.as_deref(), execution_time, &execution_args);
+
+    ExitCode::SUCCESS
+}
+
+fn execute_stdin(execution_args: ExecutionArgs) -> ExitCode {
+    let Some(input) = read_stdin() else {
+        return ExitCode::FAILURE;
+    };
+
+    match execution_args.stdin_mode.unwrap_or_default() {
+        StdinMode::Code => {
+            let lang_code = execution_args.source_encoding.decode(&input).into_owned();
+
+            execute_lang_code(&lang_code, execution_args)
+        },
+
+        StdinMode::File => {
+            let lang_file = String::from_utf8_lossy(&input);
+            let lang_file = lang_file.trim();
+            if lang_file.is_empty() {
+                eprintln!("FILE path from standard input is empty");
+
+                return ExitCode::FAILURE;
+            }
+
+            execute_lang_file(lang_file, execution_args)
+        },
+    }
+}
+
+fn execute_lang_file(lang_file: &str, execution_args: ExecutionArgs) -> ExitCode {
+    let Some(code) = read_file(lang_file) else {
+        return ExitCode::FAILURE;
+    };
+
+    log::debug!("Read {} bytes from \"{lang_file}\"", code.len());
+
+    if let Some(algorithm) = execution_args.file_hash_algorithm {
+        eprintln!("{}: {}", algorithm.name(), algorithm.hash_hex(&code));
+    }
+
+    let Some(lang_platform_api) = create_platform_api(&execution_args) else {
+        return ExitCode::FAILURE;
+    };
 
     let lang_file = Path::new(lang_file);
     let path = lang_platform_api.get_lang_path(lang_file).unwrap();
     let file_name = lang_platform_api.get_lang_file_name(lang_file).unwrap();
 
+    log::debug!("Creating interpreter with lang path \"{}\" and lang file \"{}\"", path.display(), file_name.display());
+
     let mut interpreter = Interpreter::new(
         &path.to_string_lossy(),
         Some(&file_name.to_string_lossy()),
         None,
-        Box::new(DefaultPlatformAPI::new()),
-        lang_args,
+        lang_platform_api,
+        interpreter_lang_args(&execution_args),
     );
 
-    if warnings {
-        lii::set_error_output_flag(&mut interpreter, ErrorOutputFlag::All);
-    }
+    init_interpreter(&mut interpreter, &execution_args);
+
+    log::info!("Executing Lang file \"{}\"", lang_file.display());
+    let start = Instant::now();
+    interpreter.interpret_lines(execution_args.source_encoding.decode(&code));
+    let execution_time = start.elapsed();
+    log::info!("Execution finished");
 
-    interpreter.interpret_lines(String::from_utf8_lossy(&code));
+    let execution_failure = get_execution_failure(&interpreter);
 
-    print_post_execution_output(&mut interpreter, print_translations, print_returned_value);
+    print_post_execution_output(&mut interpreter, &execution_args);
+
+    print_execution_summary(&lang_file.to_string_lossy(), execution_failure.as_deref(), execution_time, &execution_args);
 
     ExitCode::SUCCESS
 }
 
-fn print_post_execution_output(interpreter: &mut Interpreter, print_translations: bool, print_returned_value: bool) {
-    if print_translations {
+/// Returns a description of the reason why the execution failed if it was ended by an uncaught throw statement
+/// or if the final `$LANG_ERRNO` is an error (Errors which were caught or warnings are not failures)
+///
+/// The line of the error is only available for uncaught throw statements, because the interpreter does not store
+/// the position at which `$LANG_ERRNO` was set
+fn get_execution_failure(interpreter: &Interpreter) -> Option<String> {
+    let uncaught_throw = lii::is_returned_value_throw_value(interpreter);
+
+    let errno = lii::get_var(interpreter, "$LANG_ERRNO").
+            and_then(|errno| errno.borrow().int_value()).
+            unwrap_or_default();
+
+    if !uncaught_throw && errno <= 0 {
+        return None;
+    }
+
+    let mut execution_failure = if errno > 0 {
+        let error = InterpretingError::get_error_from_error_code(errno);
+
+        format!("runtime error: {} ({errno})", error.error_text())
+    }else {
+        "uncaught thrown error".to_string()
+    };
+
+    let throw_statement_pos = lii::get_throw_statement_pos(interpreter);
+    if uncaught_throw && throw_statement_pos != CodePosition::EMPTY {
+        execution_failure += &format!(" at line {}", throw_statement_pos.line_number_from());
+    }
+
+    Some(execution_failure)
+}
+
+fn print_execution_summary(name: &str, execution_failure: Option<&str>, execution_time: Duration, execution_args: &ExecutionArgs) {
+    let execution_time = execution_time.as_millis();
+
+    match (execution_args.execution_summary, execution_failure) {
+        (ExecutionSummary::Always, None) => eprintln!("{name}: OK ({execution_time}ms)"),
+        (ExecutionSummary::Always | ExecutionSummary::OnError, Some(execution_failure)) => {
+            eprintln!("{name}: FAILED ({execution_failure}, {execution_time}ms)");
+        },
+        _ => {},
+    }
+}
+
+fn print_post_execution_output(interpreter: &mut Interpreter, execution_args: &ExecutionArgs) {
+    log::trace!("Printing post execution output");
+
+    if execution_args.print_translations {
         let data = interpreter.data_ref();
         let translations = data.lang();
         println!("-------------- Translations --------------");
@@ -270,9 +1404,31 @@ fn print_post_execution_output(interpreter: &mut Interpreter, print_translations
             println!("{key} = {value}");
         }
     }
-    if print_returned_value {
-        let is_throw_value = lii::is_returned_value_throw_value(interpreter);
-        let ret_value = lii::get_and_reset_return_value(interpreter);
+
+    if execution_args.print_returned_value || execution_args.print_return_type {
+        print_returned_value(interpreter, execution_args);
+    }
+
+    if execution_args.check_assertions {
+        println!("-------------- Assertions ----------------");
+        lii::exec(interpreter, "func.testPrintResults()");
+    }
+}
+
+fn print_returned_value(interpreter: &mut Interpreter, execution_args: &ExecutionArgs) {
+    let is_throw_value = lii::is_returned_value_throw_value(interpreter);
+    let ret_value = lii::get_and_reset_return_value(interpreter);
+
+    if execution_args.print_return_type {
+        println!("-------------- Returned Type -------------");
+        if let Some(ret_value) = &ret_value {
+            println!("Returned type: {}", ret_value.data_type());
+        }else {
+            println!("Returned type: unknown");
+        }
+    }
+
+    if execution_args.print_returned_value {
         if is_throw_value {
             println!("-------------- Thrown value --------------");
             let ret_value = ret_value.and_then(|ret_value| ret_value.error_value());
@@ -0,0 +1,467 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fmt::{Debug, Display};
+use std::fs::File;
+use std::io::{Error, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use lang_interpreter::interpreter::data::function::native::NativeError;
+use lang_interpreter::interpreter::platform::PlatformAPI;
+use serde_json::{json, Value};
+use crate::diagnostics;
+
+/// Documentation of a single [PlatformAPI] method
+#[derive(Debug)]
+pub struct PlatformAPIMethodDoc {
+    pub name: &'static str,
+    pub signature: &'static str,
+    pub description: &'static str,
+    pub has_default_implementation: bool,
+}
+
+/// Documentation of all [PlatformAPI] methods in declaration order
+pub const PLATFORM_API_METHOD_DOCS: [PlatformAPIMethodDoc; 10] = [
+    PlatformAPIMethodDoc {
+        name: "get_lang_files",
+        signature: "fn get_lang_files(&self, lang_path: &Path) -> Result<Vec<PathBuf>, Error>",
+        description: "Returns all files inside the folder located at lang_path",
+        has_default_implementation: false,
+    },
+    PlatformAPIMethodDoc {
+        name: "get_lang_path",
+        signature: "fn get_lang_path(&self, lang_file: &Path) -> Result<PathBuf, Error>",
+        description: "Returns the canonical path of the folder of the file located at lang_file (Used as the base path of relative linker paths)",
+        has_default_implementation: false,
+    },
+    PlatformAPIMethodDoc {
+        name: "get_lang_file_name",
+        signature: "fn get_lang_file_name(&self, lang_file: &Path) -> Option<OsString>",
+        description: "Returns the file name of the file located at lang_file",
+        has_default_implementation: false,
+    },
+    PlatformAPIMethodDoc {
+        name: "get_lang_reader",
+        signature: "fn get_lang_reader(&self, lang_file: &Path) -> Result<Box<[u8]>, Error>",
+        description: "Returns the content of the file located at lang_file (Used for linking and translation files)",
+        has_default_implementation: false,
+    },
+    PlatformAPIMethodDoc {
+        name: "write_lang_file",
+        signature: "fn write_lang_file(&self, lang_file: &Path, translation_map: HashMap<String, String>) -> Result<(), Error>",
+        description: "Writes all translations of translation_map as a translation file to lang_file",
+        has_default_implementation: false,
+    },
+    PlatformAPIMethodDoc {
+        name: "show_input_dialog",
+        signature: "fn show_input_dialog(&self, text: &str) -> Result<String, NativeError>",
+        description: "Shows the text prompt to the user and returns the value inputted by the user",
+        has_default_implementation: false,
+    },
+    PlatformAPIMethodDoc {
+        name: "print",
+        signature: "fn print(&mut self, text: &str)",
+        description: "Prints text to standard output without newline",
+        has_default_implementation: true,
+    },
+    PlatformAPIMethodDoc {
+        name: "println",
+        signature: "fn println(&mut self, text: &str)",
+        description: "Prints text to standard output with newline",
+        has_default_implementation: true,
+    },
+    PlatformAPIMethodDoc {
+        name: "print_error",
+        signature: "fn print_error(&mut self, text: &str)",
+        description: "Prints text to error output without newline",
+        has_default_implementation: true,
+    },
+    PlatformAPIMethodDoc {
+        name: "println_error",
+        signature: "fn println_error(&mut self, text: &str)",
+        description: "Prints text to error output with newline (Used for the error output of the interpreter)",
+        has_default_implementation: true,
+    },
+];
+
+/// A [PlatformAPI] without side effects
+///
+/// All output is discarded, files can not be read or listed, writing translation files does nothing,
+/// and input dialogs return an empty text. Only paths are computed without accessing the file system.
+#[derive(Debug, Default)]
+pub struct NoPlatformAPI;
+
+impl NoPlatformAPI {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl PlatformAPI for NoPlatformAPI {
+    fn get_lang_files(&self, _: &Path) -> Result<Vec<PathBuf>, Error> {
+        Ok(Vec::new())
+    }
+
+    fn get_lang_path(&self, lang_file: &Path) -> Result<PathBuf, Error> {
+        match lang_file.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => Ok(parent.to_path_buf()),
+            _ => Ok(PathBuf::from(".")),
+        }
+    }
+
+    fn get_lang_file_name(&self, lang_file: &Path) -> Option<OsString> {
+        lang_file.file_name().map(ToOwned::to_owned)
+    }
+
+    fn get_lang_reader(&self, _: &Path) -> Result<Box<[u8]>, Error> {
+        Err(Error::new(ErrorKind::Unsupported, "The PlatformAPI is disabled"))
+    }
+
+    fn write_lang_file(&self, _: &Path, _: HashMap<String, String>) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn show_input_dialog(&self, _: &str) -> Result<String, NativeError> {
+        Ok(String::new())
+    }
+
+    fn print(&mut self, _: &str) {}
+
+    fn println(&mut self, _: &str) {}
+
+    fn print_error(&mut self, _: &str) {}
+
+    fn println_error(&mut self, _: &str) {}
+}
+
+/// Wraps another [PlatformAPI] and prints every method call including its arguments and its result to standard error
+#[derive(Debug)]
+pub struct DebugPlatformAPI {
+    platform_api: Box<dyn PlatformAPI>,
+}
+
+impl DebugPlatformAPI {
+    pub fn new(platform_api: Box<dyn PlatformAPI>) -> Self {
+        Self { platform_api }
+    }
+
+    fn format_args(args: &[&dyn Debug]) -> String {
+        args.iter().
+                map(|arg| format!("{arg:?}")).
+                collect::<Vec<_>>().
+                join(", ")
+    }
+
+    fn trace<T: Debug>(method_name: &str, args: &[&dyn Debug], ret: T) -> T {
+        eprintln!("[platform] {method_name}({}) → {ret:?}", Self::format_args(args));
+
+        ret
+    }
+
+    fn trace_without_ret(method_name: &str, args: &[&dyn Debug]) {
+        eprintln!("[platform] {method_name}({})", Self::format_args(args));
+    }
+}
+
+impl PlatformAPI for DebugPlatformAPI {
+    fn get_lang_files(&self, lang_path: &Path) -> Result<Vec<PathBuf>, Error> {
+        Self::trace("get_lang_files", &[&lang_path], self.platform_api.get_lang_files(lang_path))
+    }
+
+    fn get_lang_path(&self, lang_file: &Path) -> Result<PathBuf, Error> {
+        Self::trace("get_lang_path", &[&lang_file], self.platform_api.get_lang_path(lang_file))
+    }
+
+    fn get_lang_file_name(&self, lang_file: &Path) -> Option<OsString> {
+        Self::trace("get_lang_file_name", &[&lang_file], self.platform_api.get_lang_file_name(lang_file))
+    }
+
+    fn get_lang_reader(&self, lang_file: &Path) -> Result<Box<[u8]>, Error> {
+        let ret = self.platform_api.get_lang_reader(lang_file);
+
+        //Only print the length of the content, because the content can be very long
+        let _ = Self::trace("get_lang_reader", &[&lang_file], ret.as_ref().map(|bytes| format!("<{} bytes>", bytes.len())));
+
+        ret
+    }
+
+    fn write_lang_file(&self, lang_file: &Path, translation_map: HashMap<String, String>) -> Result<(), Error> {
+        Self::trace("write_lang_file", &[&lang_file, &translation_map], self.platform_api.write_lang_file(lang_file, translation_map.clone()))
+    }
+
+    fn show_input_dialog(&self, text: &str) -> Result<String, NativeError> {
+        Self::trace("show_input_dialog", &[&text], self.platform_api.show_input_dialog(text))
+    }
+
+    fn print(&mut self, text: &str) {
+        self.platform_api.print(text);
+
+        Self::trace_without_ret("print", &[&text]);
+    }
+
+    fn println(&mut self, text: &str) {
+        self.platform_api.println(text);
+
+        Self::trace_without_ret("println", &[&text]);
+    }
+
+    fn print_error(&mut self, text: &str) {
+        self.platform_api.print_error(text);
+
+        Self::trace_without_ret("print_error", &[&text]);
+    }
+
+    fn println_error(&mut self, text: &str) {
+        self.platform_api.println_error(text);
+
+        Self::trace_without_ret("println_error", &[&text]);
+    }
+}
+
+/// Wraps another [PlatformAPI] and writes every method call as JSON object to a JSON Lines file
+///
+/// Every event contains the name of the method (`event`), the time since the creation of the wrapper (`time_ms`),
+/// the arguments, and the result (`result` or `error`)
+#[derive(Debug)]
+pub struct LoggingPlatformAPI {
+    platform_api: Box<dyn PlatformAPI>,
+    file: RefCell<File>,
+    start: Instant,
+}
+
+impl LoggingPlatformAPI {
+    pub fn new(platform_api: Box<dyn PlatformAPI>, file: File) -> Self {
+        Self {
+            platform_api,
+            file: RefCell::new(file),
+            start: Instant::now(),
+        }
+    }
+
+    fn log(&self, event: &str, mut fields: Value) {
+        if let Some(fields) = fields.as_object_mut() {
+            fields.insert("event".to_string(), json!(event));
+            fields.insert("time_ms".to_string(), json!(self.start.elapsed().as_millis() as u64));
+        }
+
+        let _ = writeln!(self.file.borrow_mut(), "{fields}");
+    }
+
+    fn result_fields<T, E: Display>(mut fields: Value, result: &Result<T, E>, to_json: impl FnOnce(&T) -> Value) -> Value {
+        if let Some(fields) = fields.as_object_mut() {
+            match result {
+                Ok(ret) => fields.insert("result".to_string(), to_json(ret)),
+                Err(e) => fields.insert("error".to_string(), json!(e.to_string())),
+            };
+        }
+
+        fields
+    }
+}
+
+impl PlatformAPI for LoggingPlatformAPI {
+    fn get_lang_files(&self, lang_path: &Path) -> Result<Vec<PathBuf>, Error> {
+        let ret = self.platform_api.get_lang_files(lang_path);
+
+        self.log("get_lang_files", Self::result_fields(
+            json!({ "lang_path": lang_path.to_string_lossy() }),
+            &ret,
+            |files| json!(files.iter().map(|file| file.to_string_lossy()).collect::<Vec<_>>()),
+        ));
+
+        ret
+    }
+
+    fn get_lang_path(&self, lang_file: &Path) -> Result<PathBuf, Error> {
+        let ret = self.platform_api.get_lang_path(lang_file);
+
+        self.log("get_lang_path", Self::result_fields(
+            json!({ "lang_file": lang_file.to_string_lossy() }),
+            &ret,
+            |path| json!(path.to_string_lossy()),
+        ));
+
+        ret
+    }
+
+    fn get_lang_file_name(&self, lang_file: &Path) -> Option<OsString> {
+        let ret = self.platform_api.get_lang_file_name(lang_file);
+
+        self.log("get_lang_file_name", json!({
+            "lang_file": lang_file.to_string_lossy(),
+            "result": ret.as_ref().map(|file_name| file_name.to_string_lossy()),
+        }));
+
+        ret
+    }
+
+    fn get_lang_reader(&self, lang_file: &Path) -> Result<Box<[u8]>, Error> {
+        let ret = self.platform_api.get_lang_reader(lang_file);
+
+        //Only log the length of the content, because the content can be very long
+        self.log("get_lang_reader", Self::result_fields(
+            json!({ "lang_file": lang_file.to_string_lossy() }),
+            &ret,
+            |bytes| json!({ "bytes": bytes.len() }),
+        ));
+
+        ret
+    }
+
+    fn write_lang_file(&self, lang_file: &Path, translation_map: HashMap<String, String>) -> Result<(), Error> {
+        let fields = json!({
+            "lang_file": lang_file.to_string_lossy(),
+            "translation_map": translation_map,
+        });
+
+        let ret = self.platform_api.write_lang_file(lang_file, translation_map);
+
+        self.log("write_lang_file", Self::result_fields(fields, &ret, |_| Value::Null));
+
+        ret
+    }
+
+    fn show_input_dialog(&self, text: &str) -> Result<String, NativeError> {
+        let ret = self.platform_api.show_input_dialog(text);
+
+        self.log("show_input_dialog", Self::result_fields(
+            json!({ "text": text }),
+            &ret,
+            |input| json!(input),
+        ));
+
+        ret
+    }
+
+    fn print(&mut self, text: &str) {
+        self.platform_api.print(text);
+
+        self.log("print", json!({ "text": text }));
+    }
+
+    fn println(&mut self, text: &str) {
+        self.platform_api.println(text);
+
+        self.log("println", json!({ "text": text }));
+    }
+
+    fn print_error(&mut self, text: &str) {
+        self.platform_api.print_error(text);
+
+        self.log("print_error", json!({ "text": text }));
+    }
+
+    fn println_error(&mut self, text: &str) {
+        self.platform_api.println_error(text);
+
+        self.log("println_error", json!({ "text": text }));
+    }
+}
+
+/// Handles the error output (e.g. warnings and error messages of the interpreter) of an [ErrorOutputPlatformAPI]
+pub trait ErrorOutput: Debug {
+    fn print_error(&mut self, platform_api: &mut dyn PlatformAPI, text: &str);
+
+    fn println_error(&mut self, platform_api: &mut dyn PlatformAPI, text: &str);
+}
+
+/// Wraps another [PlatformAPI] and passes the error output to an [ErrorOutput] instead of the wrapped [PlatformAPI]
+///
+/// All other methods are delegated to the wrapped [PlatformAPI]
+#[derive(Debug)]
+pub struct ErrorOutputPlatformAPI<E: ErrorOutput> {
+    platform_api: Box<dyn PlatformAPI>,
+    error_output: E,
+}
+
+impl<E: ErrorOutput> ErrorOutputPlatformAPI<E> {
+    pub fn new(platform_api: Box<dyn PlatformAPI>, error_output: E) -> Self {
+        Self { platform_api, error_output }
+    }
+}
+
+impl<E: ErrorOutput> PlatformAPI for ErrorOutputPlatformAPI<E> {
+    fn get_lang_files(&self, lang_path: &Path) -> Result<Vec<PathBuf>, Error> {
+        self.platform_api.get_lang_files(lang_path)
+    }
+
+    fn get_lang_path(&self, lang_file: &Path) -> Result<PathBuf, Error> {
+        self.platform_api.get_lang_path(lang_file)
+    }
+
+    fn get_lang_file_name(&self, lang_file: &Path) -> Option<OsString> {
+        self.platform_api.get_lang_file_name(lang_file)
+    }
+
+    fn get_lang_reader(&self, lang_file: &Path) -> Result<Box<[u8]>, Error> {
+        self.platform_api.get_lang_reader(lang_file)
+    }
+
+    fn write_lang_file(&self, lang_file: &Path, translation_map: HashMap<String, String>) -> Result<(), Error> {
+        self.platform_api.write_lang_file(lang_file, translation_map)
+    }
+
+    fn show_input_dialog(&self, text: &str) -> Result<String, NativeError> {
+        self.platform_api.show_input_dialog(text)
+    }
+
+    fn print(&mut self, text: &str) {
+        self.platform_api.print(text);
+    }
+
+    fn println(&mut self, text: &str) {
+        self.platform_api.println(text);
+    }
+
+    fn print_error(&mut self, text: &str) {
+        self.error_output.print_error(&mut *self.platform_api, text);
+    }
+
+    fn println_error(&mut self, text: &str) {
+        self.error_output.println_error(&mut *self.platform_api, text);
+    }
+}
+
+/// Writes the error output to a file instead of passing it to the wrapped [PlatformAPI]
+#[derive(Debug)]
+pub struct StderrCapture {
+    file: File,
+}
+
+impl StderrCapture {
+    pub fn new(file: File) -> Self {
+        Self { file }
+    }
+}
+
+impl ErrorOutput for StderrCapture {
+    fn print_error(&mut self, _: &mut dyn PlatformAPI, text: &str) {
+        let _ = write!(self.file, "{text}");
+    }
+
+    fn println_error(&mut self, _: &mut dyn PlatformAPI, text: &str) {
+        let _ = writeln!(self.file, "{text}");
+    }
+}
+
+/// Word-wraps the error output at a fixed width before passing it to the wrapped [PlatformAPI]
+#[derive(Debug)]
+pub struct DiagnosticWrap {
+    width: usize,
+}
+
+impl DiagnosticWrap {
+    pub fn new(width: usize) -> Self {
+        Self { width }
+    }
+}
+
+impl ErrorOutput for DiagnosticWrap {
+    fn print_error(&mut self, platform_api: &mut dyn PlatformAPI, text: &str) {
+        platform_api.print_error(&diagnostics::wrap_diagnostic(text, self.width));
+    }
+
+    fn println_error(&mut self, platform_api: &mut dyn PlatformAPI, text: &str) {
+        platform_api.println_error(&diagnostics::wrap_diagnostic(text, self.width));
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::Write as _;
+use std::path::{Component, Path};
+use lang_interpreter::parser::ast::{NodeData, AST};
+
+/// Returns the names (Without the "fp." prefix) of all public functions which are defined at the top level of `ast`
+///
+/// Functions are defined with "function NAME(...) {...}" or with "fp.NAME = (...) -> {...}".
+/// Functions whose name starts with "_" are private by convention and are not returned.
+pub fn find_public_function_names(ast: &AST) -> Vec<Box<str>> {
+    let mut function_names: Vec<Box<str>> = Vec::new();
+
+    for node in ast.nodes() {
+        let function_name = match node.node_data() {
+            NodeData::FunctionDefinition(function_definition) => function_definition.function_name(),
+
+            NodeData::Assignment => match node.child_nodes() {
+                [lvalue, rvalue] if matches!(rvalue.node_data(), NodeData::FunctionDefinition(..)) => match lvalue.node_data() {
+                    NodeData::UnprocessedVariableName(variable_name) => Some(&**variable_name),
+                    _ => None,
+                },
+                _ => None,
+            },
+
+            _ => None,
+        };
+
+        let Some(function_name) = function_name.and_then(|function_name| function_name.strip_prefix("fp.")) else {
+            continue;
+        };
+
+        //Overloaded functions are defined multiple times
+        if !function_name.starts_with('_') && !function_names.iter().any(|name| **name == *function_name) {
+            function_names.push(Box::from(function_name));
+        }
+    }
+
+    function_names
+}
+
+/// Generates a langTest file which links `lang_file` and contains a stub test function for every function of `function_names`
+///
+/// The test functions are called at the end of the file, the assertions are checked with the "--check-assertions" EXECUTION_ARG
+pub fn generate_test_skeleton(lang_file: &str, function_names: &[Box<str>]) -> String {
+    let mut test_skeleton = String::new();
+
+    let _ = writeln!(test_skeleton, "# Tests for \"{lang_file}\" (Execute with the \"--check-assertions\" EXECUTION_ARG)");
+    let _ = writeln!(test_skeleton, "ln.link({})", escape_text_argument(lang_file));
+
+    for function_name in function_names {
+        let _ = writeln!(test_skeleton);
+        let _ = writeln!(test_skeleton, "fp.test_{function_name} = () -> {{");
+        let _ = writeln!(test_skeleton, "\tfunc.testUnit({function_name})");
+        let _ = writeln!(test_skeleton);
+        let _ = writeln!(test_skeleton, "\t# TODO: implement this test");
+        let _ = writeln!(test_skeleton, "}}");
+    }
+
+    if !function_names.is_empty() {
+        let _ = writeln!(test_skeleton);
+    }
+
+    for function_name in function_names {
+        let _ = writeln!(test_skeleton, "fp.test_{function_name}()");
+    }
+
+    test_skeleton
+}
+
+/// Returns the path of `lang_file` relative to `output_dir` with "/" as separator
+///
+/// The absolute path of `lang_file` is returned if there is no relative path (e.g. if the files are on different drives)
+/// and the path of `lang_file` is returned unchanged if it can not be resolved
+pub fn linked_file_path(lang_file: &Path, output_dir: &Path) -> String {
+    let (Ok(canonical_lang_file), Ok(canonical_output_dir)) = (lang_file.canonicalize(), output_dir.canonicalize()) else {
+        return lang_file.to_string_lossy().into_owned();
+    };
+
+    let common_component_count = canonical_lang_file.components().
+            zip(canonical_output_dir.components()).
+            take_while(|(lang_file_component, output_dir_component)| lang_file_component == output_dir_component).
+            count();
+
+    //Paths without a common prefix or root (e.g. on different drives) can not be relative to each other
+    let has_common_root = canonical_lang_file.components().
+            take(common_component_count).
+            any(|component| matches!(component, Component::Prefix(..) | Component::RootDir));
+    if !has_common_root {
+        return canonical_lang_file.to_string_lossy().into_owned();
+    }
+
+    let parent_dirs = canonical_output_dir.components().count() - common_component_count;
+
+    std::iter::repeat_n("..".to_string(), parent_dirs).
+            chain(canonical_lang_file.components().
+                    skip(common_component_count).
+                    map(|component| component.as_os_str().to_string_lossy().into_owned())).
+            collect::<Vec<_>>().
+            join("/")
+}
+
+/// Escapes all characters of `text` which have a special meaning in arguments of Lang function calls
+fn escape_text_argument(text: &str) -> String {
+    let mut escaped_text = String::with_capacity(text.len());
+    for char in text.chars() {
+        if matches!(char, '\\' | ',' | '$' | '&' | '(' | ')' | '#' | '{' | '}') {
+            escaped_text.push('\\');
+        }
+
+        escaped_text.push(char);
+    }
+
+    escaped_text
+}
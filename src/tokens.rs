@@ -0,0 +1,229 @@
+use std::ops::AddAssign;
+use lang_interpreter::lexer::{Lexer, Token, TokenType};
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct LineCounts {
+    pub total: usize,
+    pub code: usize,
+    pub blank: usize,
+    pub comment: usize,
+}
+
+impl LineCounts {
+    /// Counts the lines of `code`
+    ///
+    /// Lines are code lines if they contain at least one token outside of comments,
+    /// comment lines if they contain only comments, and blank lines otherwise
+    pub fn count(code: &str) -> Self {
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+        enum LineKind {
+            Blank,
+            Comment,
+            Code,
+        }
+
+        let total = code.lines().count();
+        let mut line_kinds = vec![LineKind::Blank; total];
+
+        let mut inside_comment = false;
+        for token in Lexer::new().read_tokens(code) {
+            let line_kind = match token.token_type() {
+                TokenType::StartComment | TokenType::StartDocComment => {
+                    inside_comment = true;
+
+                    LineKind::Comment
+                },
+
+                TokenType::EndComment => {
+                    inside_comment = false;
+
+                    continue;
+                },
+
+                TokenType::Whitespace | TokenType::Eol | TokenType::Eof => continue,
+
+                _ if inside_comment => LineKind::Comment,
+                _ => LineKind::Code,
+            };
+
+            let pos = token.pos();
+            for line_number in pos.line_number_from()..=pos.line_number_to() {
+                if let Some(kind) = usize::try_from(line_number - 1).ok().and_then(|index| line_kinds.get_mut(index)) {
+                    *kind = line_kind.max(*kind);
+                }
+            }
+        }
+
+        let mut line_counts = Self {
+            total,
+            ..Default::default()
+        };
+
+        for line_kind in line_kinds {
+            match line_kind {
+                LineKind::Blank => line_counts.blank += 1,
+                LineKind::Comment => line_counts.comment += 1,
+                LineKind::Code => line_counts.code += 1,
+            }
+        }
+
+        line_counts
+    }
+}
+
+impl AddAssign for LineCounts {
+    fn add_assign(&mut self, rhs: Self) {
+        self.total += rhs.total;
+        self.code += rhs.code;
+        self.blank += rhs.blank;
+        self.comment += rhs.comment;
+    }
+}
+
+/// Generates [TOKEN_TYPES] from one list of all [TokenType] variants
+///
+/// The list is matched exhaustively against [TokenType], so a new variant which is missing from the list fails to compile
+macro_rules! token_types {
+    ($($token_type:ident),* $(,)?) => {
+        /// All token types in the declaration order of [TokenType]
+        pub const TOKEN_TYPES: [TokenType; [$(TokenType::$token_type),*].len()] = [$(TokenType::$token_type),*];
+
+        const _: fn(TokenType) = |token_type| match token_type {
+            $(TokenType::$token_type)|* => {},
+        };
+    };
+}
+
+token_types! {
+    Other,
+    LiteralNull,
+    LiteralText,
+    LiteralNumber,
+    ArgumentSeparator,
+    EscapeSequence,
+    ParserFunctionIdentifier,
+    Identifier,
+    Operator,
+    Assignment,
+    OpeningBracket,
+    ClosingBracket,
+    OpeningBlockBracket,
+    ClosingBlockBracket,
+    SingleLineTextQuotes,
+    StartMultilineText,
+    EndMultilineText,
+    StartComment,
+    StartDocComment,
+    EndComment,
+    LineContinuation,
+    Whitespace,
+    Eol,
+    Eof,
+    LexerError,
+}
+
+/// Formats `token` with its start and end position: `[3:5..3:8] IDENTIFIER "foo"`
+pub fn format_token_with_span(token: &Token) -> String {
+    let pos = token.pos();
+
+    format!(
+        "[{}:{}..{}:{}] {} \"{}\"",
+        pos.line_number_from(), pos.column_from(),
+        pos.line_number_to(), pos.column_to(),
+        token.token_type(), token.value().escape_debug(),
+    )
+}
+
+/// Removes all comment tokens (Including the content and line continuations inside of comments) from `tokens`
+pub fn strip_comments(tokens: Vec<Token>) -> Vec<Token> {
+    let mut inside_comment = false;
+
+    tokens.into_iter().filter(|token| {
+        match token.token_type() {
+            TokenType::StartComment | TokenType::StartDocComment => {
+                inside_comment = true;
+
+                false
+            },
+
+            TokenType::EndComment => {
+                inside_comment = false;
+
+                false
+            },
+
+            _ => !inside_comment,
+        }
+    }).collect()
+}
+
+/// Removes all whitespace and end of line tokens from `tokens`
+pub fn strip_whitespace(tokens: Vec<Token>) -> Vec<Token> {
+    tokens.into_iter().
+            filter(|token| !matches!(token.token_type(), TokenType::Whitespace | TokenType::Eol)).
+            collect()
+}
+
+/// Returns the canonical form of `tokens` without comments and positions (One `TYPE "VALUE"` line per token)
+///
+/// Whitespace between non-structural tokens (e.g. inside of texts and function arguments) is kept, because it is part
+/// of the values of the program. Indentation, trailing whitespace, and whitespace next to brackets, argument separators,
+/// and assignments is removed. Line breaks are kept because they terminate statements (Consecutive line breaks are
+/// collapsed to a single line break). Token streams which only differ in indentation, in whitespace next to structural tokens,
+/// or in comments have the same canonical form.
+pub fn canonical_token_stream(tokens: Vec<Token>) -> String {
+    fn is_structural(token_type: TokenType) -> bool {
+        matches!(
+            token_type,
+            TokenType::ArgumentSeparator | TokenType::Assignment |
+            TokenType::OpeningBracket | TokenType::ClosingBracket |
+            TokenType::OpeningBlockBracket | TokenType::ClosingBlockBracket |
+            TokenType::Eol | TokenType::Eof,
+        )
+    }
+
+    let mut canonical_tokens: Vec<(TokenType, &str)> = Vec::new();
+    let mut pending_whitespace: Vec<&str> = Vec::new();
+
+    let tokens = strip_comments(tokens);
+    for token in &tokens {
+        let token_type = token.token_type();
+        if token_type == TokenType::Whitespace {
+            pending_whitespace.push(token.value());
+
+            continue;
+        }
+
+        //Whitespace is only kept between two non-structural tokens of the same line
+        let is_whitespace_kept = !is_structural(token_type) &&
+                canonical_tokens.last().is_some_and(|(last_token_type, _)| !is_structural(*last_token_type));
+        if is_whitespace_kept {
+            canonical_tokens.extend(pending_whitespace.drain(..).map(|value| (TokenType::Whitespace, value)));
+        }else {
+            pending_whitespace.clear();
+        }
+
+        if matches!(token_type, TokenType::Eol | TokenType::Eof) {
+            //Remove leading, repeated, and trailing line breaks
+            if canonical_tokens.last().is_some_and(|(last_token_type, _)| *last_token_type == TokenType::Eol) {
+                canonical_tokens.pop();
+            }
+
+            if token_type == TokenType::Eol && canonical_tokens.is_empty() {
+                continue;
+            }
+        }
+
+        let value = match token_type {
+            //The values of these token types contain the surrounding whitespace
+            TokenType::Assignment | TokenType::ArgumentSeparator => token.value().trim(),
+            _ => token.value(),
+        };
+
+        canonical_tokens.push((token_type, value));
+    }
+
+    canonical_tokens.into_iter().
+            map(|(token_type, value)| format!("{token_type} \"{}\"\n", value.escape_debug())).
+            collect()
+}
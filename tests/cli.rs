@@ -0,0 +1,151 @@
+use std::process::{Command, Output, Stdio};
+
+fn run_lang(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_lang")).
+            args(args).
+            stdin(Stdio::null()).
+            output().
+            unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn json_lang_args_are_visible_inside_of_functions() {
+    let output = run_lang(&[
+        "-e", "fp.f = () -> fn.println(&LANG_ARGS)\nfp.f()\nfn.println(fn.typeOf(parser.op(&LANG_ARGS[0])))",
+        "--eval-args", "[1.5,\"b\"]",
+    ]);
+
+    assert_eq!(stdout(&output), "[1.5, b]\nDOUBLE\n");
+}
+
+#[test]
+fn execution_summary_reports_success_if_error_was_caught() {
+    let output = run_lang(&[
+        "-e", "try {\n\tthrow $LANG_ERROR_DIV_BY_ZERO\n}catch {\n\tfn.println(caught)\n}",
+        "--execution-summary=always",
+    ]);
+
+    assert_eq!(stdout(&output), "caught\n");
+    assert!(stderr(&output).contains("<shell>: OK ("), "{}", stderr(&output));
+}
+
+#[test]
+fn execution_summary_reports_uncaught_error() {
+    let output = run_lang(&["-e", "fn.println(a)\nthrow $LANG_ERROR_DIV_BY_ZERO"]);
+
+    assert!(stderr(&output).contains("<shell>: FAILED (runtime error: Dividing by 0 (14) at line 2, "), "{}", stderr(&output));
+}
+
+#[test]
+fn generated_test_skeleton_links_file_with_special_characters() {
+    let test_dir = std::env::temp_dir().join(format!("lang-cli-test-skeleton-{}", std::process::id()));
+    let lang_dir = test_dir.join("d,1 (x)");
+    let output_file = test_dir.join("tests").join("test_lib.lang");
+    std::fs::create_dir_all(&lang_dir).unwrap();
+    std::fs::create_dir_all(output_file.parent().unwrap()).unwrap();
+    std::fs::write(lang_dir.join("lib.lang"), "function add($a, $b) {\n\treturn parser.op($a + $b)\n}\nfn.println(linked)\n").unwrap();
+
+    let output = run_lang(&[
+        "--generate-test-skeleton", &lang_dir.join("lib.lang").to_string_lossy(),
+        "--output", &output_file.to_string_lossy(),
+    ]);
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let test_skeleton = std::fs::read_to_string(&output_file).unwrap();
+    assert!(test_skeleton.contains("ln.link(../d\\,1 \\(x\\)/lib.lang)\n"), "{test_skeleton}");
+
+    let output = run_lang(&[&output_file.to_string_lossy(), "--check-assertions"]);
+    assert!(stdout(&output).starts_with("linked\n"), "{}", stdout(&output));
+
+    std::fs::remove_dir_all(&test_dir).unwrap();
+}
+
+#[test]
+fn missing_execution_args_file_is_reported() {
+    let output = run_lang(&["-e", "fn.println(a)", "--execution-args-file", "lang-cli-test-missing-args-file.txt"]);
+
+    assert!(!output.status.success());
+    assert_eq!(stdout(&output), "");
+    assert!(stderr(&output).starts_with("FILE can not be read "), "{}", stderr(&output));
+}
+
+#[test]
+fn encoding_detection_warning_is_printed_on_execution() {
+    let lang_file = std::env::temp_dir().join(format!("lang-cli-test-latin1-{}.lang", std::process::id()));
+    std::fs::write(&lang_file, b"fn.println(gr\xfc\xdf)\n").unwrap();
+
+    let output = run_lang(&[&lang_file.to_string_lossy(), "--encoding=detect"]);
+    std::fs::remove_file(&lang_file).unwrap();
+
+    assert!(stderr(&output).contains("[WARN] Encoding detection is not confident enough"), "{}", stderr(&output));
+}
+
+#[test]
+fn fatal_error_is_printed_to_stderr_if_log_file_is_used() {
+    let log_file = std::env::temp_dir().join(format!("lang-cli-test-log-{}.log", std::process::id()));
+
+    let output = run_lang(&["lang-cli-test-missing-file.lang", "--log-file", &log_file.to_string_lossy()]);
+    let _ = std::fs::remove_file(&log_file);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).starts_with("FILE can not be read "), "{}", stderr(&output));
+}
+
+#[test]
+fn check_file_exists_reports_all_missing_imports() {
+    let test_dir = std::env::temp_dir().join(format!("lang-cli-test-imports-{}", std::process::id()));
+    std::fs::create_dir_all(&test_dir).unwrap();
+    let lang_file = test_dir.join("main.lang");
+    std::fs::write(&lang_file, "ln.link(imp.lang)\nln.link(ab.lang)\nln.link($x.lang)\n").unwrap();
+
+    let output = run_lang(&["--check-file-exists", &lang_file.to_string_lossy()]);
+    std::fs::remove_dir_all(&test_dir).unwrap();
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("imp.lang\" (Imported by "), "{}", stderr(&output));
+    assert!(stderr(&output).contains("ab.lang\" (Imported by "), "{}", stderr(&output));
+    assert!(stderr(&output).contains("Note: The file path of \"ln.link\""), "{}", stderr(&output));
+    assert!(stderr(&output).contains("2 imported file(s) are missing"), "{}", stderr(&output));
+}
+
+fn token_hash(code: &str) -> String {
+    let lang_file = std::env::temp_dir().join(format!("lang-cli-test-token-hash-{}.lang", std::process::id()));
+    std::fs::write(&lang_file, code).unwrap();
+
+    let output = run_lang(&["-printTokens", "--hash-only", &lang_file.to_string_lossy()]);
+    std::fs::remove_file(&lang_file).unwrap();
+
+    stdout(&output)
+}
+
+#[test]
+fn token_hash_keeps_significant_whitespace() {
+    assert_ne!(token_hash("$x=1+2\nfn.println($x)\n"), token_hash("$x = 1 + 2\nfn.println($x)\n"));
+    assert_ne!(token_hash("fn.println(a   b)\n"), token_hash("fn.println(a b)\n"));
+
+    assert_eq!(token_hash("\tfn.println( a , b )  # Comment\n"), token_hash("fn.println(a,b)\n"));
+}
+
+#[test]
+fn misplaced_global_option_is_hinted() {
+    let output = run_lang(&["--hint", "--introspect-api", "--format=json", "--output-json-pretty"]);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("Hint: \"--output-json-pretty\" is a GLOBAL_OPTION and must be placed before"), "{}", stderr(&output));
+    assert!(stdout(&output).contains("[GLOBAL_OPTIONs]... COMMAND"), "{}", stdout(&output));
+}
+
+#[test]
+fn execution_summary_reports_errno_error_without_line() {
+    let output = run_lang(&["-e", "fn.divi(1, 0)"]);
+
+    assert!(stderr(&output).contains("<shell>: FAILED (runtime error: Dividing by 0 (14), "), "{}", stderr(&output));
+}